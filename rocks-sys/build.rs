@@ -312,7 +312,9 @@ fn main() {
         .warnings(false)
         .flag("-std=c++11")
         .include(".")
+        .file("rocks/backupable_db.cc")
         .file("rocks/cache.cc")
+        .file("rocks/checkpoint.cc")
         .file("rocks/comparator.cc")
         .file("rocks/convenience.cc")
         .file("rocks/db.cc")
@@ -323,21 +325,27 @@ fn main() {
         .file("rocks/iterator.cc")
         .file("rocks/merge_operator.cc")
         .file("rocks/metadata.cc")
+        .file("rocks/optimistic_transaction_db.cc")
         .file("rocks/options.cc")
+        .file("rocks/options_util.cc")
         .file("rocks/perf_context.cc")
         .file("rocks/perf_level.cc")
         .file("rocks/rate_limiter.cc")
         .file("rocks/slice.cc")
         .file("rocks/snapshot.cc")
+        .file("rocks/sst_file_manager.cc")
         .file("rocks/sst_file_writer.cc")
         .file("rocks/statistics.cc")
         .file("rocks/status.cc")
         .file("rocks/table.cc")
         .file("rocks/table_properties.cc")
+        .file("rocks/transaction.cc")
+        .file("rocks/transaction_db.cc")
         .file("rocks/transaction_log.cc")
         .file("rocks/universal_compaction.cc")
         .file("rocks/util.cc")
         .file("rocks/write_batch.cc")
+        .file("rocks/write_batch_with_index.cc")
         .file("rocks/write_buffer_manager.cc")
         .file("rocks/debug.cc")
         .file("rocks/listener.cc")
@@ -0,0 +1,101 @@
+#include "rocksdb/utilities/backupable_db.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+extern "C" {
+// rocks_backup_engine_options_t
+rocks_backup_engine_options_t* rocks_backup_engine_options_create(const char* backup_dir, const size_t backup_dir_len) {
+  return new rocks_backup_engine_options_t{BackupableDBOptions(std::string(backup_dir, backup_dir_len))};
+}
+
+void rocks_backup_engine_options_destroy(rocks_backup_engine_options_t* options) { delete options; }
+
+void rocks_backup_engine_options_set_share_table_files(rocks_backup_engine_options_t* options, unsigned char v) {
+  options->rep.share_table_files = v;
+}
+
+void rocks_backup_engine_options_set_sync(rocks_backup_engine_options_t* options, unsigned char v) {
+  options->rep.sync = v;
+}
+
+void rocks_backup_engine_options_set_destroy_old_data(rocks_backup_engine_options_t* options, unsigned char v) {
+  options->rep.destroy_old_data = v;
+}
+
+void rocks_backup_engine_options_set_backup_log_files(rocks_backup_engine_options_t* options, unsigned char v) {
+  options->rep.backup_log_files = v;
+}
+
+void rocks_backup_engine_options_set_max_background_operations(rocks_backup_engine_options_t* options, int v) {
+  options->rep.max_background_operations = v;
+}
+
+// rocks_backup_engine_t
+rocks_backup_engine_t* rocks_backup_engine_open(const rocks_backup_engine_options_t* options, rocks_env_t* env,
+                                                rocks_status_t** status) {
+  BackupEngine* engine = nullptr;
+  if (SaveError(status, BackupEngine::Open(env->rep, options->rep, &engine))) {
+    return nullptr;
+  }
+  return new rocks_backup_engine_t{engine};
+}
+
+void rocks_backup_engine_close(rocks_backup_engine_t* engine) {
+  delete engine->rep;
+  delete engine;
+}
+
+void rocks_backup_engine_create_new_backup(rocks_backup_engine_t* engine, rocks_db_t* db,
+                                           unsigned char flush_before_backup, rocks_status_t** status) {
+  SaveError(status, engine->rep->CreateNewBackup(db->rep, flush_before_backup));
+}
+
+void rocks_backup_engine_purge_old_backups(rocks_backup_engine_t* engine, uint32_t num_backups_to_keep,
+                                           rocks_status_t** status) {
+  SaveError(status, engine->rep->PurgeOldBackups(num_backups_to_keep));
+}
+
+void rocks_backup_engine_delete_backup(rocks_backup_engine_t* engine, uint32_t backup_id, rocks_status_t** status) {
+  SaveError(status, engine->rep->DeleteBackup(backup_id));
+}
+
+void rocks_backup_engine_verify_backup(rocks_backup_engine_t* engine, uint32_t backup_id, rocks_status_t** status) {
+  SaveError(status, engine->rep->VerifyBackup(backup_id));
+}
+
+rocks_backup_infos_t* rocks_backup_engine_get_backup_info(rocks_backup_engine_t* engine) {
+  auto infos = new rocks_backup_infos_t;
+  engine->rep->GetBackupInfo(&infos->rep);
+  return infos;
+}
+
+void rocks_backup_engine_restore_db_from_latest_backup(rocks_backup_engine_t* engine, const char* db_dir,
+                                                       const size_t db_dir_len, const char* wal_dir,
+                                                       const size_t wal_dir_len, unsigned char keep_log_files,
+                                                       rocks_status_t** status) {
+  SaveError(status, engine->rep->RestoreDBFromLatestBackup(std::string(db_dir, db_dir_len),
+                                                           std::string(wal_dir, wal_dir_len),
+                                                           RestoreOptions(keep_log_files)));
+}
+
+// rocks_backup_infos_t
+void rocks_backup_infos_destroy(rocks_backup_infos_t* infos) { delete infos; }
+
+size_t rocks_backup_infos_size(rocks_backup_infos_t* infos) { return infos->rep.size(); }
+
+uint32_t rocks_backup_infos_nth_backup_id(rocks_backup_infos_t* infos, size_t nth) {
+  return infos->rep[nth].backup_id;
+}
+
+int64_t rocks_backup_infos_nth_timestamp(rocks_backup_infos_t* infos, size_t nth) {
+  return infos->rep[nth].timestamp;
+}
+
+uint64_t rocks_backup_infos_nth_size(rocks_backup_infos_t* infos, size_t nth) { return infos->rep[nth].size; }
+
+uint32_t rocks_backup_infos_nth_number_files(rocks_backup_infos_t* infos, size_t nth) {
+  return infos->rep[nth].number_files;
+}
+}
@@ -38,6 +38,9 @@ typedef struct rocks_status_t rocks_status_t;
 /* rate_limiter.h */
 typedef struct rocks_ratelimiter_t rocks_ratelimiter_t;
 
+/* sst_file_manager.h */
+typedef struct rocks_sst_file_manager_t rocks_sst_file_manager_t;
+
 /* env */
 typedef struct rocks_envoptions_t rocks_envoptions_t;
 typedef struct rocks_logger_t rocks_logger_t;
@@ -144,6 +147,27 @@ typedef struct rocks_external_file_ingestion_info_t rocks_external_file_ingestio
 /* thread_status */
 typedef struct rocks_thread_status_t rocks_thread_status_t;
 
+/* checkpoint */
+typedef struct rocks_checkpoint_t rocks_checkpoint_t;
+
+/* backupable_db */
+typedef struct rocks_backup_engine_options_t rocks_backup_engine_options_t;
+typedef struct rocks_backup_engine_t rocks_backup_engine_t;
+typedef struct rocks_backup_infos_t rocks_backup_infos_t;
+
+/* write_batch_with_index */
+typedef struct rocks_writebatch_with_index_t rocks_writebatch_with_index_t;
+
+/* transaction */
+typedef struct rocks_transaction_t rocks_transaction_t;
+
+/* optimistic_transaction_db */
+typedef struct rocks_optimistic_transaction_db_t rocks_optimistic_transaction_db_t;
+
+/* transaction_db */
+typedef struct rocks_transactiondb_options_t rocks_transactiondb_options_t;
+typedef struct rocks_transactiondb_t rocks_transactiondb_t;
+
 /* aux */
 typedef struct cxx_string_vector_t cxx_string_vector_t;
 typedef struct cxx_string_t cxx_string_t; /* std::string */
@@ -169,6 +193,8 @@ const char* rocks_pinnable_slice_data(rocks_pinnable_slice_t* s);
 
 size_t rocks_pinnable_slice_size(rocks_pinnable_slice_t* s);
 
+void rocks_pinnable_slice_reset(rocks_pinnable_slice_t* s);
+
 /* options.h */
 /*    start */
 rocks_options_t* rocks_options_create();
@@ -189,6 +215,12 @@ rocks_dboptions_t* rocks_dboptions_create_from_options(rocks_options_t* options)
 
 rocks_cfoptions_t* rocks_cfoptions_create_from_options(rocks_options_t* options);
 
+rocks_options_t* rocks_options_copy(rocks_options_t* options);
+
+rocks_dboptions_t* rocks_dboptions_copy(rocks_dboptions_t* options);
+
+rocks_cfoptions_t* rocks_cfoptions_copy(rocks_cfoptions_t* options);
+
 // cfoptions
 
 void rocks_cfoptions_optimize_for_small_db(rocks_cfoptions_t* opt);
@@ -207,15 +239,14 @@ void rocks_cfoptions_set_comparator_by_trait(rocks_cfoptions_t* opt, void* cp_tr
 
 void rocks_cfoptions_set_compaction_filter_by_trait(rocks_cfoptions_t* opt, void* filter_trait_obj);
 
+void rocks_cfoptions_set_compaction_filter_factory_by_trait(rocks_cfoptions_t* opt, void* factory_trait_obj);
+
 void rocks_cfoptions_set_bitwise_comparator(rocks_cfoptions_t* opt, unsigned char reversed);
 
 /*
 void rocks_cfoptions_set_compaction_filter(
                                        rocks_options_t* opt,
                                        rocks_compactionfilter_t* filter);
-
-void rocks_cfoptions_set_compaction_filter_factory(rocks_options_t* opt,
-rocks_compactionfilterfactory_t* factory);
 */
 
 void rocks_cfoptions_set_write_buffer_size(rocks_cfoptions_t* opt, size_t s);
@@ -351,8 +382,7 @@ void rocks_dboptions_set_env(rocks_dboptions_t* opt, rocks_env_t* env);
 
 void rocks_dboptions_set_ratelimiter(rocks_dboptions_t* opt, rocks_ratelimiter_t* limiter);
 
-// void rocks_dboptions_set_sst_file_manager(rocks_dboptions_t* opt,
-// rocks_sst_file_manager_t* manager);
+void rocks_dboptions_set_sst_file_manager(rocks_dboptions_t* opt, rocks_sst_file_manager_t* manager);
 
 void rocks_dboptions_set_info_log(rocks_dboptions_t* opt, rocks_logger_t* l);
 
@@ -638,6 +668,8 @@ void rocks_db_drop_column_family(rocks_db_t* db, rocks_column_family_handle_t* h
 void rocks_db_destroy_column_family_handle(rocks_db_t* db, rocks_column_family_handle_t* handle,
                                            rocks_status_t** status);
 
+rocks_column_family_handle_t* rocks_column_family_handle_clone(rocks_column_family_handle_t* handle);
+
 void rocks_column_family_handle_destroy(rocks_column_family_handle_t* handle);
 
 void rocks_db_put(rocks_db_t* db, const rocks_writeoptions_t* options, const char* key, size_t keylen, const char* val,
@@ -730,6 +762,8 @@ unsigned char rocks_db_get_int_property_cf(rocks_db_t* db, rocks_column_family_h
 unsigned char rocks_db_get_aggregated_int_property(rocks_db_t* db, const char* prop, const size_t prop_len,
                                                    uint64_t* value);
 
+void rocks_db_reset_stats(rocks_db_t* db, rocks_status_t** status);
+
 void rocks_db_compact_range(rocks_db_t* db, const char* start_key, size_t start_key_len, const char* limit_key,
                             size_t limit_key_len);
 
@@ -752,9 +786,10 @@ void rocks_db_set_options_cf(rocks_db_t* db, rocks_column_family_handle_t* colum
 void rocks_db_set_db_options(rocks_db_t* db, size_t num_options, const char* const* keys, const size_t* key_lens,
                              const char* const* vals, const size_t* val_lens, rocks_status_t** status);
 
-void rocks_db_compact_files(rocks_db_t* db, rocks_compaction_options_t* opt, size_t num_files,
-                            const char* const* file_names, const size_t* file_name_lens, const int output_level,
-                            const int output_path_id, rocks_status_t** status);
+void rocks_db_compact_files_cf(rocks_db_t* db, rocks_compaction_options_t* opt,
+                               rocks_column_family_handle_t* column_family, size_t num_files,
+                               const char* const* file_names, const size_t* file_name_lens, const int output_level,
+                               const int output_path_id, rocks_status_t** status);
 
 void rocks_db_pause_background_work(rocks_db_t* db, rocks_status_t** status);
 void rocks_db_continue_background_work(rocks_db_t* db, rocks_status_t** status);
@@ -787,6 +822,8 @@ void rocks_db_flush(rocks_db_t* db, rocks_flushoptions_t* options, rocks_status_
 void rocks_db_flush_cf(rocks_db_t* db, rocks_flushoptions_t* options, rocks_column_family_handle_t* column_family,
                        rocks_status_t** status);
 
+void rocks_db_flush_wal(rocks_db_t* db, unsigned char sync, rocks_status_t** status);
+
 void rocks_db_sync_wal(rocks_db_t* db, rocks_status_t** status);
 
 uint64_t rocks_db_get_latest_sequence_number(rocks_db_t* db);
@@ -821,6 +858,10 @@ void rocks_db_get_db_identity(rocks_db_t* db,
                               void* identity,  // *mut String
                               rocks_status_t** status);
 
+rocks_cfoptions_t* rocks_db_get_options(rocks_db_t* db, rocks_column_family_handle_t* column_family);
+
+rocks_dboptions_t* rocks_db_get_db_options(rocks_db_t* db);
+
 rocks_table_props_collection_t* rocks_db_get_properties_of_all_tables(rocks_db_t* db, rocks_column_family_handle_t* cf,
                                                                       rocks_status_t** status);
 
@@ -842,11 +883,45 @@ rocks_ratelimiter_t* rocks_ratelimiter_create(int64_t rate_bytes_per_sec, int64_
 
 void rocks_ratelimiter_destroy(rocks_ratelimiter_t* limiter);
 
+rocks_ratelimiter_t* rocks_ratelimiter_clone(rocks_ratelimiter_t* limiter);
+
+void rocks_ratelimiter_set_bytes_per_second(rocks_ratelimiter_t* limiter, int64_t bytes_per_second);
+
+int64_t rocks_ratelimiter_get_single_burst_bytes(rocks_ratelimiter_t* limiter);
+
+int64_t rocks_ratelimiter_get_total_bytes_through(rocks_ratelimiter_t* limiter);
+
+int64_t rocks_ratelimiter_get_total_requests(rocks_ratelimiter_t* limiter);
+
+int64_t rocks_ratelimiter_get_bytes_per_second(rocks_ratelimiter_t* limiter);
+
+/* sst_file_manager.h */
+rocks_sst_file_manager_t* rocks_sst_file_manager_create(rocks_env_t* env, rocks_logger_t* info_log,
+                                                        const char* trash_dir, size_t trash_dir_len,
+                                                        int64_t rate_bytes_per_sec,
+                                                        unsigned char delete_existing_trash, rocks_status_t** status);
+
+void rocks_sst_file_manager_destroy(rocks_sst_file_manager_t* manager);
+
+rocks_sst_file_manager_t* rocks_sst_file_manager_clone(rocks_sst_file_manager_t* manager);
+
+void rocks_sst_file_manager_set_max_allowed_space_usage(rocks_sst_file_manager_t* manager,
+                                                        uint64_t max_allowed_space);
+
+unsigned char rocks_sst_file_manager_is_max_allowed_space_reached(rocks_sst_file_manager_t* manager);
+
+uint64_t rocks_sst_file_manager_get_total_size(rocks_sst_file_manager_t* manager);
+
+int64_t rocks_sst_file_manager_get_delete_rate_bytes_per_second(rocks_sst_file_manager_t* manager);
+
+void rocks_sst_file_manager_set_delete_rate_bytes_per_second(rocks_sst_file_manager_t* manager, int64_t delete_rate);
+
 /* env.h */
 rocks_env_t* rocks_create_default_env();
 
 rocks_env_t* rocks_create_mem_env();
 rocks_env_t* rocks_create_timed_env();
+rocks_env_t* rocks_create_env_by_trait(void* fs_trait_obj);
 
 void rocks_env_destroy(rocks_env_t* env);
 
@@ -898,6 +973,8 @@ void rocks_envoptions_set_compaction_readahead_size(rocks_envoptions_t* opt, siz
 void rocks_envoptions_set_random_access_max_buffer_size(rocks_envoptions_t* opt, size_t val);
 void rocks_envoptions_set_writable_file_max_buffer_size(rocks_envoptions_t* opt, size_t val);
 
+rocks_logger_t* rocks_logger_create_by_trait(void* logger_trait_obj);
+
 void rocks_logger_destroy(rocks_logger_t* logger);
 
 void rocks_logger_log(rocks_logger_t* logger, int log_level, const char* msg_ptr, size_t msg_len);
@@ -925,6 +1002,8 @@ rocks_writebatch_t* rocks_writebatch_create();
 
 rocks_writebatch_t* rocks_writebatch_create_with_reserved_bytes(size_t size);
 
+rocks_writebatch_t* rocks_writebatch_create_from(const char* rep, size_t size);
+
 void rocks_writebatch_destroy(rocks_writebatch_t* b);
 
 void rocks_writebatch_clear(rocks_writebatch_t* b);
@@ -994,10 +1073,17 @@ void rocks_writebatch_iterate(rocks_writebatch_t* b, void* trait_obj, rocks_stat
 
 const char* rocks_writebatch_data(rocks_writebatch_t* b, size_t* size);
 
+size_t rocks_writebatch_get_data_size(rocks_writebatch_t* b);
+
 void rocks_writebatch_set_save_point(rocks_writebatch_t* b);
 
 void rocks_writebatch_rollback_to_save_point(rocks_writebatch_t* b, rocks_status_t** status);
 
+void rocks_writebatch_mark_wal_termination_point(rocks_writebatch_t* b);
+
+void rocks_writebatch_get_wal_termination_point(rocks_writebatch_t* b, size_t* size, int* count,
+                                                uint32_t* content_flags);
+
 unsigned char rocks_writebatch_has_put(rocks_writebatch_t* b);
 unsigned char rocks_writebatch_has_delete(rocks_writebatch_t* b);
 unsigned char rocks_writebatch_has_single_delete(rocks_writebatch_t* b);
@@ -1108,6 +1194,10 @@ const char* rocks_iter_key(const rocks_iterator_t* iter, size_t* klen);
 
 const char* rocks_iter_value(const rocks_iterator_t* iter, size_t* vlen);
 
+char* rocks_iter_copy_key(const rocks_iterator_t* iter, size_t* klen);
+
+char* rocks_iter_copy_value(const rocks_iterator_t* iter, size_t* vlen);
+
 void rocks_iter_get_status(const rocks_iterator_t* iter, rocks_status_t** status);
 
 void rocks_iter_get_property(const rocks_iterator_t* iter, const char* prop, size_t prop_len, void* value,
@@ -1128,6 +1218,8 @@ rocks_cache_t* rocks_cache_create_clock(size_t capacity, int num_shard_bits, cha
 
 void rocks_cache_destroy(rocks_cache_t* cache);
 
+rocks_cache_t* rocks_cache_clone(rocks_cache_t* cache);
+
 void rocks_cache_set_capacity(rocks_cache_t* cache, size_t capacity);
 
 size_t rocks_cache_get_capacity(rocks_cache_t* cache);
@@ -1138,6 +1230,11 @@ size_t rocks_cache_get_pinned_usage(rocks_cache_t* cache);
 
 const char* rocks_cache_name(rocks_cache_t* cache);
 
+void rocks_cache_insert(rocks_cache_t* cache, const char* key, size_t key_len, const char* val, size_t val_len,
+                        size_t charge, rocks_status_t** status);
+
+void rocks_cache_erase(rocks_cache_t* cache, const char* key, size_t key_len);
+
 /* persistent_cache */
 rocks_persistent_cache_t* rocks_new_persistent_cache(const rocks_env_t* env, const char* path, size_t path_len,
                                                      uint64_t size, const rocks_logger_t* log,
@@ -1360,9 +1457,15 @@ void rocks_get_supported_compressions_destroy(int* ptr);
 void rocks_cancel_all_background_work(rocks_db_t* db, unsigned char wait);
 void rocks_db_delete_files_in_range(rocks_db_t* db, rocks_column_family_handle_t* column_family, const char* begin_ptr,
                                     size_t begin_len, const char* end_ptr, size_t end_len, rocks_status_t** status);
+void rocks_db_suggest_compact_range(rocks_db_t* db, rocks_column_family_handle_t* column_family, const char* begin_ptr,
+                                    size_t begin_len, const char* end_ptr, size_t end_len, rocks_status_t** status);
 // cxx_string_destroy must be called for following
 cxx_string_t* rocks_get_string_from_dboptions(rocks_dboptions_t* opts);
 cxx_string_t* rocks_get_string_from_cfoptions(rocks_cfoptions_t* opts);
+rocks_cfoptions_t* rocks_get_cfoptions_from_string(rocks_cfoptions_t* base_options, const char* opts_str,
+                                                   size_t opts_len, rocks_status_t** status);
+rocks_dboptions_t* rocks_get_dboptions_from_string(rocks_dboptions_t* base_options, const char* opts_str,
+                                                   size_t opts_len, rocks_status_t** status);
 
 /* table_properties */
 void rocks_table_props_collection_destroy(rocks_table_props_collection_t* coll);
@@ -1395,6 +1498,7 @@ uint64_t rocks_table_props_get_num_data_blocks(rocks_table_props_t* prop);
 uint64_t rocks_table_props_get_num_entries(rocks_table_props_t* prop);
 uint64_t rocks_table_props_get_format_version(rocks_table_props_t* prop);
 uint64_t rocks_table_props_get_fixed_key_len(rocks_table_props_t* prop);
+uint64_t rocks_table_props_get_num_deletions(rocks_table_props_t* prop);
 uint32_t rocks_table_props_get_column_family_id(rocks_table_props_t* prop);
 const char* rocks_table_props_get_column_family_name(rocks_table_props_t* prop, size_t* len);
 const char* rocks_table_props_get_filter_policy_name(rocks_table_props_t* prop, size_t* len);
@@ -1429,6 +1533,8 @@ const char* rocks_user_collected_props_iter_value(rocks_user_collected_props_ite
 /* write_buffer_manager */
 rocks_write_buffer_manager_t* rocks_write_buffer_manager_create(size_t buffer_size);
 
+rocks_write_buffer_manager_t* rocks_write_buffer_manager_create_with_cache(size_t buffer_size, rocks_cache_t* cache);
+
 void rocks_write_buffer_manager_destroy(rocks_write_buffer_manager_t* manager);
 
 unsigned char rocks_write_buffer_manager_enabled(rocks_write_buffer_manager_t* manager);
@@ -1570,6 +1676,144 @@ int rocks_thread_status_get_operation_stage(const rocks_thread_status_t* status)
 const uint64_t* rocks_thread_status_get_op_properties(const rocks_thread_status_t* status, size_t* len);
 int rocks_thread_status_get_state_type(const rocks_thread_status_t* status);
 
+/* checkpoint */
+rocks_checkpoint_t* rocks_checkpoint_create(rocks_db_t* db, rocks_status_t** status);
+
+void rocks_checkpoint_destroy(rocks_checkpoint_t* checkpoint);
+
+void rocks_checkpoint_create_checkpoint(rocks_checkpoint_t* checkpoint, const char* checkpoint_dir,
+                                        const size_t checkpoint_dir_len, uint64_t log_size_for_flush,
+                                        rocks_status_t** status);
+
+/* options_util */
+rocks_dboptions_t* rocks_load_latest_options(const char* dbpath, size_t dbpath_len, rocks_env_t* env,
+                                             unsigned char ignore_unknown_options, size_t* num_cfs, char*** cf_names,
+                                             rocks_cfoptions_t*** cf_options, rocks_status_t** status);
+
+rocks_dboptions_t* rocks_load_options_from_file(const char* options_file_name, size_t options_file_name_len,
+                                                rocks_env_t* env, unsigned char ignore_unknown_options,
+                                                size_t* num_cfs, char*** cf_names, rocks_cfoptions_t*** cf_options,
+                                                rocks_status_t** status);
+
+void rocks_load_options_cf_options_list_destroy(rocks_cfoptions_t** list);
+
+void rocks_get_latest_options_file_name(const char* dbpath, size_t dbpath_len, rocks_env_t* env,
+                                        void* options_file_name,  // *mut String
+                                        rocks_status_t** status);
+
+/* backupable_db */
+rocks_backup_engine_options_t* rocks_backup_engine_options_create(const char* backup_dir, const size_t backup_dir_len);
+void rocks_backup_engine_options_destroy(rocks_backup_engine_options_t* options);
+void rocks_backup_engine_options_set_share_table_files(rocks_backup_engine_options_t* options, unsigned char v);
+void rocks_backup_engine_options_set_sync(rocks_backup_engine_options_t* options, unsigned char v);
+void rocks_backup_engine_options_set_destroy_old_data(rocks_backup_engine_options_t* options, unsigned char v);
+void rocks_backup_engine_options_set_backup_log_files(rocks_backup_engine_options_t* options, unsigned char v);
+void rocks_backup_engine_options_set_max_background_operations(rocks_backup_engine_options_t* options, int v);
+
+rocks_backup_engine_t* rocks_backup_engine_open(const rocks_backup_engine_options_t* options, rocks_env_t* env,
+                                                rocks_status_t** status);
+void rocks_backup_engine_close(rocks_backup_engine_t* engine);
+void rocks_backup_engine_create_new_backup(rocks_backup_engine_t* engine, rocks_db_t* db,
+                                           unsigned char flush_before_backup, rocks_status_t** status);
+void rocks_backup_engine_purge_old_backups(rocks_backup_engine_t* engine, uint32_t num_backups_to_keep,
+                                           rocks_status_t** status);
+void rocks_backup_engine_delete_backup(rocks_backup_engine_t* engine, uint32_t backup_id, rocks_status_t** status);
+void rocks_backup_engine_verify_backup(rocks_backup_engine_t* engine, uint32_t backup_id, rocks_status_t** status);
+rocks_backup_infos_t* rocks_backup_engine_get_backup_info(rocks_backup_engine_t* engine);
+void rocks_backup_engine_restore_db_from_latest_backup(rocks_backup_engine_t* engine, const char* db_dir,
+                                                       const size_t db_dir_len, const char* wal_dir,
+                                                       const size_t wal_dir_len, unsigned char keep_log_files,
+                                                       rocks_status_t** status);
+
+void rocks_backup_infos_destroy(rocks_backup_infos_t* infos);
+size_t rocks_backup_infos_size(rocks_backup_infos_t* infos);
+uint32_t rocks_backup_infos_nth_backup_id(rocks_backup_infos_t* infos, size_t nth);
+int64_t rocks_backup_infos_nth_timestamp(rocks_backup_infos_t* infos, size_t nth);
+uint64_t rocks_backup_infos_nth_size(rocks_backup_infos_t* infos, size_t nth);
+uint32_t rocks_backup_infos_nth_number_files(rocks_backup_infos_t* infos, size_t nth);
+
+/* write_batch_with_index */
+rocks_writebatch_with_index_t* rocks_writebatch_with_index_create(size_t reserved_bytes, unsigned char overwrite_key);
+void rocks_writebatch_with_index_destroy(rocks_writebatch_with_index_t* b);
+void rocks_writebatch_with_index_clear(rocks_writebatch_with_index_t* b);
+int rocks_writebatch_with_index_count(rocks_writebatch_with_index_t* b);
+void rocks_writebatch_with_index_put(rocks_writebatch_with_index_t* b, const char* key, size_t klen, const char* val,
+                                     size_t vlen);
+void rocks_writebatch_with_index_put_cf(rocks_writebatch_with_index_t* b,
+                                        rocks_column_family_handle_t* column_family, const char* key, size_t klen,
+                                        const char* val, size_t vlen);
+void rocks_writebatch_with_index_merge(rocks_writebatch_with_index_t* b, const char* key, size_t klen,
+                                       const char* val, size_t vlen);
+void rocks_writebatch_with_index_merge_cf(rocks_writebatch_with_index_t* b,
+                                          rocks_column_family_handle_t* column_family, const char* key, size_t klen,
+                                          const char* val, size_t vlen);
+void rocks_writebatch_with_index_delete(rocks_writebatch_with_index_t* b, const char* key, size_t klen);
+void rocks_writebatch_with_index_delete_cf(rocks_writebatch_with_index_t* b,
+                                           rocks_column_family_handle_t* column_family, const char* key,
+                                           size_t klen);
+void rocks_writebatch_with_index_single_delete(rocks_writebatch_with_index_t* b, const char* key, size_t klen);
+void rocks_writebatch_with_index_single_delete_cf(rocks_writebatch_with_index_t* b,
+                                                  rocks_column_family_handle_t* column_family, const char* key,
+                                                  size_t klen);
+rocks_writebatch_t* rocks_writebatch_with_index_get_writebatch(rocks_writebatch_with_index_t* b);
+void rocks_writebatch_with_index_get_from_batch(rocks_writebatch_with_index_t* b, const rocks_dboptions_t* options,
+                                                const char* key, size_t klen, void* value, rocks_status_t** status);
+void rocks_writebatch_with_index_get_from_batch_cf(rocks_writebatch_with_index_t* b,
+                                                   rocks_column_family_handle_t* column_family,
+                                                   const rocks_dboptions_t* options, const char* key, size_t klen,
+                                                   void* value, rocks_status_t** status);
+void rocks_writebatch_with_index_get_from_batch_and_db(rocks_writebatch_with_index_t* b, rocks_db_t* db,
+                                                       const rocks_readoptions_t* options, const char* key,
+                                                       size_t klen, rocks_pinnable_slice_t* value,
+                                                       rocks_status_t** status);
+void rocks_writebatch_with_index_get_from_batch_and_db_cf(rocks_writebatch_with_index_t* b, rocks_db_t* db,
+                                                          const rocks_readoptions_t* options,
+                                                          rocks_column_family_handle_t* column_family,
+                                                          const char* key, size_t klen,
+                                                          rocks_pinnable_slice_t* value, rocks_status_t** status);
+
+/* transaction */
+void rocks_transaction_destroy(rocks_transaction_t* txn);
+void rocks_transaction_set_lock_timeout(rocks_transaction_t* txn, int64_t timeout);
+void rocks_transaction_commit(rocks_transaction_t* txn, rocks_status_t** status);
+void rocks_transaction_rollback(rocks_transaction_t* txn, rocks_status_t** status);
+void rocks_transaction_get(rocks_transaction_t* txn, const rocks_readoptions_t* options, const char* key,
+                           size_t klen, void* value, rocks_status_t** status);
+void rocks_transaction_get_for_update(rocks_transaction_t* txn, const rocks_readoptions_t* options, const char* key,
+                                      size_t klen, void* value, rocks_status_t** status);
+void rocks_transaction_put(rocks_transaction_t* txn, const char* key, size_t klen, const char* val, size_t vlen,
+                           rocks_status_t** status);
+void rocks_transaction_merge(rocks_transaction_t* txn, const char* key, size_t klen, const char* val, size_t vlen,
+                             rocks_status_t** status);
+void rocks_transaction_delete(rocks_transaction_t* txn, const char* key, size_t klen, rocks_status_t** status);
+
+/* optimistic_transaction_db */
+rocks_optimistic_transaction_db_t* rocks_optimistic_transaction_db_open(const rocks_options_t* options,
+                                                                        const char* name, size_t name_len,
+                                                                        rocks_status_t** status);
+void rocks_optimistic_transaction_db_close(rocks_optimistic_transaction_db_t* txn_db);
+rocks_db_t* rocks_optimistic_transaction_db_get_base_db(rocks_optimistic_transaction_db_t* txn_db);
+void rocks_optimistic_transaction_db_close_base_db(rocks_db_t* base_db);
+rocks_transaction_t* rocks_optimistic_transaction_db_begin_transaction(rocks_optimistic_transaction_db_t* txn_db,
+                                                                       const rocks_writeoptions_t* write_options);
+
+/* transaction_db */
+rocks_transactiondb_options_t* rocks_transactiondb_options_create();
+void rocks_transactiondb_options_destroy(rocks_transactiondb_options_t* options);
+void rocks_transactiondb_options_set_max_num_locks(rocks_transactiondb_options_t* options, int64_t v);
+void rocks_transactiondb_options_set_num_stripes(rocks_transactiondb_options_t* options, size_t v);
+void rocks_transactiondb_options_set_transaction_lock_timeout(rocks_transactiondb_options_t* options, int64_t v);
+void rocks_transactiondb_options_set_default_lock_timeout(rocks_transactiondb_options_t* options, int64_t v);
+
+rocks_transactiondb_t* rocks_transactiondb_open(const rocks_options_t* options,
+                                                const rocks_transactiondb_options_t* txn_db_options, const char* name,
+                                                size_t name_len, rocks_status_t** status);
+void rocks_transactiondb_close(rocks_transactiondb_t* txn_db);
+rocks_db_t* rocks_transactiondb_get_base_db(rocks_transactiondb_t* txn_db);
+void rocks_transactiondb_close_base_db(rocks_db_t* base_db);
+rocks_transaction_t* rocks_transactiondb_begin_transaction(rocks_transactiondb_t* txn_db,
+                                                           const rocks_writeoptions_t* write_options);
+
 /* aux */
 void free(void* p);
 
@@ -1587,6 +1831,7 @@ void cxx_string_vector_destory(cxx_string_vector_t* v);
 size_t cxx_string_vector_size(cxx_string_vector_t* v);
 const char* cxx_string_vector_nth(cxx_string_vector_t* v, size_t index);
 size_t cxx_string_vector_nth_size(cxx_string_vector_t* v, size_t index);
+void cxx_string_vector_push_back(cxx_string_vector_t* v, const char* p, size_t len);
 
 #ifdef __cplusplus
 }
@@ -7,6 +7,10 @@ using namespace rocksdb;
 
 using std::shared_ptr;
 
+namespace {
+void DeleteCachedString(const Slice& key, void* value) { delete reinterpret_cast<std::string*>(value); }
+}
+
 extern "C" {
 rocks_cache_t* rocks_cache_create_lru(size_t capacity, int num_shard_bits, char strict_capacity_limit,
                                       double high_pri_pool_ratio) {
@@ -27,6 +31,8 @@ rocks_cache_t* rocks_cache_create_clock(size_t capacity, int num_shard_bits, cha
 
 void rocks_cache_destroy(rocks_cache_t* cache) { delete cache; }
 
+rocks_cache_t* rocks_cache_clone(rocks_cache_t* cache) { return new rocks_cache_t{cache->rep}; }
+
 void rocks_cache_set_capacity(rocks_cache_t* cache, size_t capacity) { cache->rep->SetCapacity(capacity); }
 
 size_t rocks_cache_get_capacity(rocks_cache_t* cache) { return cache->rep->GetCapacity(); }
@@ -36,6 +42,16 @@ size_t rocks_cache_get_usage(rocks_cache_t* cache) { return cache->rep->GetUsage
 size_t rocks_cache_get_pinned_usage(rocks_cache_t* cache) { return cache->rep->GetPinnedUsage(); }
 
 const char* rocks_cache_name(rocks_cache_t* cache) { return cache->rep->Name(); }
+
+void rocks_cache_insert(rocks_cache_t* cache, const char* key, size_t key_len, const char* val, size_t val_len,
+                        size_t charge, rocks_status_t** status) {
+  auto value = new std::string(val, val_len);
+  SaveError(status, cache->rep->Insert(Slice(key, key_len), value, charge, &DeleteCachedString));
+}
+
+void rocks_cache_erase(rocks_cache_t* cache, const char* key, size_t key_len) {
+  cache->rep->Erase(Slice(key, key_len));
+}
 }
 
 // persistent_cache
@@ -0,0 +1,27 @@
+#include "rocksdb/utilities/checkpoint.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+extern "C" {
+rocks_checkpoint_t* rocks_checkpoint_create(rocks_db_t* db, rocks_status_t** status) {
+  Checkpoint* checkpoint = nullptr;
+  if (SaveError(status, Checkpoint::Create(db->rep, &checkpoint))) {
+    return nullptr;
+  }
+  return new rocks_checkpoint_t{checkpoint};
+}
+
+void rocks_checkpoint_destroy(rocks_checkpoint_t* checkpoint) {
+  delete checkpoint->rep;
+  delete checkpoint;
+}
+
+void rocks_checkpoint_create_checkpoint(rocks_checkpoint_t* checkpoint, const char* checkpoint_dir,
+                                        const size_t checkpoint_dir_len, uint64_t log_size_for_flush,
+                                        rocks_status_t** status) {
+  SaveError(status,
+            checkpoint->rep->CreateCheckpoint(std::string(checkpoint_dir, checkpoint_dir_len), log_size_for_flush));
+}
+}
@@ -1,4 +1,5 @@
 #include "rocksdb/convenience.h"
+#include "rocksdb/experimental.h"
 
 #include "rocks/ctypes.hpp"
 
@@ -25,7 +26,16 @@ void rocks_db_delete_files_in_range(rocks_db_t* db, rocks_column_family_handle_t
                                     size_t begin_len, const char* end_ptr, size_t end_len, rocks_status_t** status) {
   auto begin = Slice(begin_ptr, begin_len);
   auto end = Slice(end_ptr, end_len);
-  auto st = DeleteFilesInRange(db->rep, column_family->rep, &begin, &end);
+  auto st = DeleteFilesInRange(db->rep, column_family->rep, (begin_ptr ? &begin : nullptr), (end_ptr ? &end : nullptr));
+  SaveError(status, std::move(st));
+}
+
+void rocks_db_suggest_compact_range(rocks_db_t* db, rocks_column_family_handle_t* column_family, const char* begin_ptr,
+                                    size_t begin_len, const char* end_ptr, size_t end_len, rocks_status_t** status) {
+  auto begin = Slice(begin_ptr, begin_len);
+  auto end = Slice(end_ptr, end_len);
+  auto st = experimental::SuggestCompactRange(db->rep, column_family->rep, (begin_ptr ? &begin : nullptr),
+                                              (end_ptr ? &end : nullptr));
   SaveError(status, std::move(st));
 }
 
@@ -50,4 +60,24 @@ cxx_string_t* rocks_get_string_from_cfoptions(rocks_cfoptions_t* opts) {
     return nullptr;
   }
 }
+
+rocks_cfoptions_t* rocks_get_cfoptions_from_string(rocks_cfoptions_t* base_options, const char* opts_str,
+                                                   size_t opts_len, rocks_status_t** status) {
+  ColumnFamilyOptions new_options;
+  auto st = GetColumnFamilyOptionsFromString(base_options->rep, std::string(opts_str, opts_len), &new_options);
+  if (SaveError(status, std::move(st))) {
+    return nullptr;
+  }
+  return new rocks_cfoptions_t{new_options};
+}
+
+rocks_dboptions_t* rocks_get_dboptions_from_string(rocks_dboptions_t* base_options, const char* opts_str,
+                                                   size_t opts_len, rocks_status_t** status) {
+  DBOptions new_options;
+  auto st = GetDBOptionsFromString(base_options->rep, std::string(opts_str, opts_len), &new_options);
+  if (SaveError(status, std::move(st))) {
+    return nullptr;
+  }
+  return new rocks_dboptions_t{new_options};
+}
 }
@@ -17,17 +17,27 @@
 #include "rocksdb/persistent_cache.h"
 #include "rocksdb/rate_limiter.h"
 #include "rocksdb/slice_transform.h"
+#include "rocksdb/sst_file_manager.h"
 #include "rocksdb/sst_file_writer.h"
 #include "rocksdb/status.h"
 #include "rocksdb/table.h"
 #include "rocksdb/table_properties.h"
 #include "rocksdb/transaction_log.h"
+#include "rocksdb/utilities/backupable_db.h"
+#include "rocksdb/utilities/checkpoint.h"
 #include "rocksdb/utilities/debug.h"
+#include "rocksdb/utilities/optimistic_transaction_db.h"
+#include "rocksdb/utilities/transaction.h"
+#include "rocksdb/utilities/transaction_db.h"
+#include "rocksdb/utilities/write_batch_with_index.h"
 #include "rocksdb/write_buffer_manager.h"
 
 #include "rust_export.h"
 
+#include <cstdio>
+#include <deque>
 #include <iostream>
+#include <vector>
 
 using namespace rocksdb;
 
@@ -65,6 +75,16 @@ static bool SaveError(rocks_status_t** status, const Status&& s) {
   }
 }
 
+// takes the status returned from rust, nullptr means OK
+static Status TakeRustStatus(rocks_status_t* status) {
+  if (status == nullptr) {
+    return Status::OK();
+  }
+  Status s = status->rep;
+  delete status;
+  return s;
+}
+
 static char* CopyString(const std::string& str) {
   char* result = reinterpret_cast<char*>(malloc(sizeof(char) * str.size()));
   memcpy(result, str.data(), sizeof(char) * str.size());
@@ -141,6 +161,27 @@ struct rocks_mergeoperator_t : public MergeOperator {
     }
     return ret != 0;
   }
+
+  virtual bool PartialMergeMulti(const Slice& key, const std::deque<Slice>& operand_list, std::string* new_value,
+                                 Logger* logger) const override {
+    // rust side takes operands as ptr/len pairs
+    std::vector<const char*> operand_ptrs;
+    std::vector<size_t> operand_lens;
+    for (const auto& operand : operand_list) {
+      operand_ptrs.push_back(operand.data());
+      operand_lens.push_back(operand.size());
+    }
+    char* nval = nullptr;
+    size_t nval_len = 0;
+    auto ret = rust_merge_operator_call_partial_merge_multi(this->obj, &key, operand_ptrs.data(), operand_lens.data(),
+                                                            operand_list.size(), &nval, &nval_len, logger);
+    if (ret) {
+      new_value->assign(nval, nval_len);
+      // NOTE: this drops Vec<u8>
+      rust_drop_vec_u8(nval, nval_len);
+    }
+    return (bool)ret;
+  }
 };
 
 struct rocks_associative_mergeoperator_t : public AssociativeMergeOperator {
@@ -194,6 +235,10 @@ struct rocks_ratelimiter_t {
   shared_ptr<RateLimiter> rep;
 };
 
+struct rocks_sst_file_manager_t {
+  shared_ptr<SstFileManager> rep;
+};
+
 /* env */
 struct rocks_envoptions_t {
   EnvOptions rep;
@@ -207,6 +252,194 @@ struct rocks_env_t {
   bool is_default;
 };
 
+/* rust env */
+struct rocks_rust_sequential_file_t : public SequentialFile {
+  void* obj;  // rust Box<trait obj>
+
+  rocks_rust_sequential_file_t(void* trait_obj) : obj(trait_obj) {}
+
+  ~rocks_rust_sequential_file_t() { rust_sequential_file_drop(this->obj); }
+
+  Status Read(size_t n, Slice* result, char* scratch) override {
+    size_t read = 0;
+    auto st = TakeRustStatus(rust_sequential_file_read(this->obj, scratch, n, &read));
+    *result = Slice(scratch, read);
+    return st;
+  }
+
+  Status Skip(uint64_t n) override { return TakeRustStatus(rust_sequential_file_skip(this->obj, n)); }
+};
+
+struct rocks_rust_random_access_file_t : public RandomAccessFile {
+  void* obj;  // rust Box<trait obj>
+
+  rocks_rust_random_access_file_t(void* trait_obj) : obj(trait_obj) {}
+
+  ~rocks_rust_random_access_file_t() { rust_random_access_file_drop(this->obj); }
+
+  Status Read(uint64_t offset, size_t n, Slice* result, char* scratch) const override {
+    size_t read = 0;
+    auto st = TakeRustStatus(rust_random_access_file_read(this->obj, offset, scratch, n, &read));
+    *result = Slice(scratch, read);
+    return st;
+  }
+};
+
+struct rocks_rust_writable_file_t : public WritableFile {
+  void* obj;  // rust Box<trait obj>
+
+  rocks_rust_writable_file_t(void* trait_obj) : obj(trait_obj) {}
+
+  ~rocks_rust_writable_file_t() { rust_writable_file_drop(this->obj); }
+
+  Status Append(const Slice& data) override { return TakeRustStatus(rust_writable_file_append(this->obj, &data)); }
+
+  Status Close() override { return TakeRustStatus(rust_writable_file_close(this->obj)); }
+
+  Status Flush() override { return TakeRustStatus(rust_writable_file_flush(this->obj)); }
+
+  Status Sync() override { return TakeRustStatus(rust_writable_file_sync(this->obj)); }
+};
+
+// directories are not fsynced in rust env
+struct rocks_rust_directory_t : public Directory {
+  Status Fsync() override { return Status::OK(); }
+};
+
+// file locks are left to the rust env
+struct rocks_rust_file_lock_t : public FileLock {};
+
+// routes file system operations to rust, others are handled by Env::Default()
+struct rocks_rust_env_t : public EnvWrapper {
+  void* obj;  // rust Box<trait obj>
+
+  rocks_rust_env_t(void* trait_obj) : EnvWrapper(Env::Default()), obj(trait_obj) {}
+
+  ~rocks_rust_env_t() { rust_env_drop(this->obj); }
+
+  Status NewSequentialFile(const std::string& fname, std::unique_ptr<SequentialFile>* result,
+                           const EnvOptions& options) override {
+    void* file = nullptr;
+    auto st = TakeRustStatus(rust_env_new_sequential_file(this->obj, fname.data(), fname.size(), &file));
+    if (st.ok()) {
+      result->reset(new rocks_rust_sequential_file_t{file});
+    }
+    return st;
+  }
+
+  Status NewRandomAccessFile(const std::string& fname, std::unique_ptr<RandomAccessFile>* result,
+                             const EnvOptions& options) override {
+    void* file = nullptr;
+    auto st = TakeRustStatus(rust_env_new_random_access_file(this->obj, fname.data(), fname.size(), &file));
+    if (st.ok()) {
+      result->reset(new rocks_rust_random_access_file_t{file});
+    }
+    return st;
+  }
+
+  Status NewWritableFile(const std::string& fname, std::unique_ptr<WritableFile>* result,
+                         const EnvOptions& options) override {
+    void* file = nullptr;
+    auto st = TakeRustStatus(rust_env_new_writable_file(this->obj, fname.data(), fname.size(), &file));
+    if (st.ok()) {
+      result->reset(new rocks_rust_writable_file_t{file});
+    }
+    return st;
+  }
+
+  Status NewDirectory(const std::string& name, std::unique_ptr<Directory>* result) override {
+    result->reset(new rocks_rust_directory_t);
+    return Status::OK();
+  }
+
+  Status FileExists(const std::string& fname) override {
+    return rust_env_file_exists(this->obj, fname.data(), fname.size()) ? Status::OK() : Status::NotFound();
+  }
+
+  Status GetChildren(const std::string& dir, std::vector<std::string>* result) override {
+    cxx_string_vector_t children;
+    auto st = TakeRustStatus(rust_env_get_children(this->obj, dir.data(), dir.size(), &children));
+    if (st.ok()) {
+      *result = std::move(children.rep);
+    }
+    return st;
+  }
+
+  Status DeleteFile(const std::string& fname) override {
+    return TakeRustStatus(rust_env_delete_file(this->obj, fname.data(), fname.size()));
+  }
+
+  Status CreateDir(const std::string& dirname) override {
+    return TakeRustStatus(rust_env_create_dir(this->obj, dirname.data(), dirname.size()));
+  }
+
+  Status CreateDirIfMissing(const std::string& dirname) override {
+    return TakeRustStatus(rust_env_create_dir_if_missing(this->obj, dirname.data(), dirname.size()));
+  }
+
+  Status DeleteDir(const std::string& dirname) override {
+    return TakeRustStatus(rust_env_delete_dir(this->obj, dirname.data(), dirname.size()));
+  }
+
+  Status GetFileSize(const std::string& fname, uint64_t* file_size) override {
+    return TakeRustStatus(rust_env_get_file_size(this->obj, fname.data(), fname.size(), file_size));
+  }
+
+  Status GetFileModificationTime(const std::string& fname, uint64_t* file_mtime) override {
+    return TakeRustStatus(rust_env_get_file_modification_time(this->obj, fname.data(), fname.size(), file_mtime));
+  }
+
+  Status RenameFile(const std::string& src, const std::string& target) override {
+    return TakeRustStatus(rust_env_rename_file(this->obj, src.data(), src.size(), target.data(), target.size()));
+  }
+
+  Status LockFile(const std::string& fname, FileLock** lock) override {
+    *lock = new rocks_rust_file_lock_t;
+    return Status::OK();
+  }
+
+  Status UnlockFile(FileLock* lock) override {
+    delete lock;
+    return Status::OK();
+  }
+
+  // info log is disabled unless DBOptions::info_log is set
+  Status NewLogger(const std::string& fname, std::shared_ptr<Logger>* result) override {
+    return Status::NotSupported("info log file in rust env");
+  }
+};
+
+/* rust logger */
+struct rocks_rust_logger_t : public Logger {
+  void* obj;  // rust Box<trait obj>
+
+  rocks_rust_logger_t(void* trait_obj) : Logger(), obj(trait_obj) {}
+
+  ~rocks_rust_logger_t() { rust_logger_drop(this->obj); }
+
+  void Logv(const char* format, va_list ap) override { Logv(InfoLogLevel::INFO_LEVEL, format, ap); }
+
+  void Logv(const InfoLogLevel log_level, const char* format, va_list ap) override {
+    if (log_level < GetInfoLogLevel()) {
+      return;
+    }
+    va_list backup_ap;
+    va_copy(backup_ap, ap);
+    auto len = vsnprintf(nullptr, 0, format, backup_ap);
+    va_end(backup_ap);
+    if (len < 0) {
+      return;
+    }
+    std::vector<char> buf(len + 1);
+    vsnprintf(buf.data(), buf.size(), format, ap);
+    rust_logger_logv(this->obj, static_cast<int>(log_level), buf.data(), len);
+  }
+
+  void LogHeader(const char* format, va_list ap) override { Logv(InfoLogLevel::HEADER_LEVEL, format, ap); }
+
+  void Flush() override { rust_logger_flush(this->obj); }
+};
+
 /* snapshot*/
 struct rocks_snapshot_t {
   const Snapshot* rep;
@@ -327,6 +560,22 @@ struct rocks_compaction_filter_t : public CompactionFilter {
   const char* Name() const override { return rust_compaction_filter_name(this->obj); }
 };
 
+struct rocks_compaction_filter_factory_t : public CompactionFilterFactory {
+  void* obj;  // rust Box<trait obj>
+
+  rocks_compaction_filter_factory_t(void* trait_obj) : obj(trait_obj) {}
+
+  ~rocks_compaction_filter_factory_t() { rust_compaction_filter_factory_drop(this->obj); }
+
+  std::unique_ptr<CompactionFilter> CreateCompactionFilter(const CompactionFilter::Context& context) override {
+    // Box<Box<CompactionFilter>>
+    auto filter = rust_compaction_filter_factory_create_compaction_filter(this->obj, &context);
+    return std::unique_ptr<CompactionFilter>(new rocks_compaction_filter_t{filter});
+  }
+
+  const char* Name() const override { return rust_compaction_filter_factory_name(this->obj); }
+};
+
 /* slice_transform */
 struct rocks_slice_transform_t : public SliceTransform {
   void* obj;  // rust Box<trait obj>
@@ -558,6 +807,45 @@ struct rocks_persistent_cache_t {
   std::shared_ptr<PersistentCache> rep;
 };
 
+/* checkpoint */
+struct rocks_checkpoint_t {
+  Checkpoint* rep;
+};
+
+/* backupable_db */
+struct rocks_backup_engine_options_t {
+  BackupableDBOptions rep;
+};
+struct rocks_backup_engine_t {
+  BackupEngine* rep;
+};
+struct rocks_backup_infos_t {
+  std::vector<BackupInfo> rep;
+};
+
+/* write_batch_with_index */
+struct rocks_writebatch_with_index_t {
+  std::unique_ptr<WriteBatchWithIndex> rep;
+};
+
+/* transaction */
+struct rocks_transaction_t {
+  Transaction* rep;
+};
+
+/* optimistic_transaction_db */
+struct rocks_optimistic_transaction_db_t {
+  OptimisticTransactionDB* rep;
+};
+
+/* transaction_db */
+struct rocks_transactiondb_options_t {
+  TransactionDBOptions rep;
+};
+struct rocks_transactiondb_t {
+  TransactionDB* rep;
+};
+
 #ifdef __cplusplus
 }
 #endif
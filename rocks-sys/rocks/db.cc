@@ -159,6 +159,10 @@ void rocks_db_destroy_column_family_handle(rocks_db_t* db, rocks_column_family_h
   delete handle;
 }
 
+rocks_column_family_handle_t* rocks_column_family_handle_clone(rocks_column_family_handle_t* handle) {
+  return new rocks_column_family_handle_t{handle->rep};
+}
+
 // NOTE: never delete handle-rep, since it'll be deleted by DestroyColumnFamilyHandle
 void rocks_column_family_handle_destroy(rocks_column_family_handle_t* handle) {
   // if already freed by rocks_db_destroy_column_family_handle
@@ -408,6 +412,8 @@ unsigned char rocks_db_get_aggregated_int_property(rocks_db_t* db, const char* p
   return has;
 }
 
+void rocks_db_reset_stats(rocks_db_t* db, rocks_status_t** status) { SaveError(status, db->rep->ResetStats()); }
+
 void rocks_db_compact_range(rocks_db_t* db, const char* start_key, size_t start_key_len, const char* limit_key,
                             size_t limit_key_len) {
   Slice a, b;
@@ -470,14 +476,15 @@ void rocks_db_set_db_options(rocks_db_t* db, size_t num_options, const char* con
   SaveError(status, std::move(st));
 }
 
-void rocks_db_compact_files(rocks_db_t* db, rocks_compaction_options_t* opt, size_t num_files,
-                            const char* const* file_names, const size_t* file_name_lens, const int output_level,
-                            const int output_path_id, rocks_status_t** status) {
+void rocks_db_compact_files_cf(rocks_db_t* db, rocks_compaction_options_t* opt,
+                               rocks_column_family_handle_t* column_family, size_t num_files,
+                               const char* const* file_names, const size_t* file_name_lens, const int output_level,
+                               const int output_path_id, rocks_status_t** status) {
   std::vector<std::string> input_file_names;
   for (auto i = 0; i < num_files; i++) {
     input_file_names.push_back(std::string(file_names[i], file_name_lens[i]));
   }
-  auto st = db->rep->CompactFiles(opt->rep, input_file_names, output_level, output_path_id);
+  auto st = db->rep->CompactFiles(opt->rep, column_family->rep, input_file_names, output_level, output_path_id);
   SaveError(status, std::move(st));
 }
 
@@ -561,6 +568,10 @@ void rocks_db_flush_cf(rocks_db_t* db, rocks_flushoptions_t* options, rocks_colu
   SaveError(status, std::move(db->rep->Flush(options->rep, column_family->rep)));
 }
 
+void rocks_db_flush_wal(rocks_db_t* db, unsigned char sync, rocks_status_t** status) {
+  SaveError(status, db->rep->FlushWAL(sync));
+}
+
 void rocks_db_sync_wal(rocks_db_t* db, rocks_status_t** status) { SaveError(status, std::move(db->rep->SyncWAL())); }
 
 uint64_t rocks_db_get_latest_sequence_number(rocks_db_t* db) { return db->rep->GetLatestSequenceNumber(); }
@@ -654,6 +665,12 @@ void rocks_db_get_db_identity(rocks_db_t* db,
   }
 }
 
+rocks_cfoptions_t* rocks_db_get_options(rocks_db_t* db, rocks_column_family_handle_t* column_family) {
+  return new rocks_cfoptions_t{ColumnFamilyOptions(db->rep->GetOptions(column_family->rep))};
+}
+
+rocks_dboptions_t* rocks_db_get_db_options(rocks_db_t* db) { return new rocks_dboptions_t{db->rep->GetDBOptions()}; }
+
 rocks_table_props_collection_t* rocks_db_get_properties_of_all_tables(rocks_db_t* db, rocks_column_family_handle_t* cf,
                                                                       rocks_status_t** status) {
   auto coll = new rocks_table_props_collection_t;
@@ -28,6 +28,13 @@ rocks_env_t* rocks_create_timed_env() {
   return result;
 }
 
+rocks_env_t* rocks_create_env_by_trait(void* fs_trait_obj) {
+  rocks_env_t* result = new rocks_env_t;
+  result->rep = new rocks_rust_env_t(fs_trait_obj);
+  result->is_default = false;
+  return result;
+}
+
 void rocks_env_destroy(rocks_env_t* env) {
   if (!env->is_default) delete env->rep;
   delete env;
@@ -163,6 +170,10 @@ void rocks_envoptions_set_rate_limiter(rocks_envoptions_t* opt, ....) {
 }
 
 extern "C" {
+rocks_logger_t* rocks_logger_create_by_trait(void* logger_trait_obj) {
+  return new rocks_logger_t{shared_ptr<Logger>(new rocks_rust_logger_t(logger_trait_obj))};
+}
+
 void rocks_logger_destroy(rocks_logger_t* logger) { delete logger; }
 
 void rocks_logger_log(rocks_logger_t* logger, int log_level, const char* msg_ptr, size_t msg_len) {
@@ -41,6 +41,22 @@ const char* rocks_iter_value(const rocks_iterator_t* iter, size_t* vlen) {
   return s.data();
 }
 
+char* rocks_iter_copy_key(const rocks_iterator_t* iter, size_t* klen) {
+  Slice s = iter->rep->key();
+  *klen = s.size();
+  char* result = reinterpret_cast<char*>(malloc(sizeof(char) * s.size()));
+  memcpy(result, s.data(), sizeof(char) * s.size());
+  return result;
+}
+
+char* rocks_iter_copy_value(const rocks_iterator_t* iter, size_t* vlen) {
+  Slice s = iter->rep->value();
+  *vlen = s.size();
+  char* result = reinterpret_cast<char*>(malloc(sizeof(char) * s.size()));
+  memcpy(result, s.data(), sizeof(char) * s.size());
+  return result;
+}
+
 void rocks_iter_get_status(const rocks_iterator_t* iter, rocks_status_t** status) {
   SaveError(status, std::move(iter->rep->status()));
 }
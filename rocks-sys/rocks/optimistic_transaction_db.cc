@@ -0,0 +1,34 @@
+#include "rocksdb/utilities/optimistic_transaction_db.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+extern "C" {
+rocks_optimistic_transaction_db_t* rocks_optimistic_transaction_db_open(const rocks_options_t* options,
+                                                                        const char* name, size_t name_len,
+                                                                        rocks_status_t** status) {
+  OptimisticTransactionDB* txn_db = nullptr;
+  if (SaveError(status, OptimisticTransactionDB::Open(options->rep, std::string(name, name_len), &txn_db))) {
+    return nullptr;
+  }
+  return new rocks_optimistic_transaction_db_t{txn_db};
+}
+
+void rocks_optimistic_transaction_db_close(rocks_optimistic_transaction_db_t* txn_db) {
+  delete txn_db->rep;
+  delete txn_db;
+}
+
+// returned wrapper does not own the DB, release with rocks_optimistic_transaction_db_close_base_db
+rocks_db_t* rocks_optimistic_transaction_db_get_base_db(rocks_optimistic_transaction_db_t* txn_db) {
+  return new rocks_db_t{txn_db->rep->GetBaseDB()};
+}
+
+void rocks_optimistic_transaction_db_close_base_db(rocks_db_t* base_db) { delete base_db; }
+
+rocks_transaction_t* rocks_optimistic_transaction_db_begin_transaction(rocks_optimistic_transaction_db_t* txn_db,
+                                                                       const rocks_writeoptions_t* write_options) {
+  return new rocks_transaction_t{txn_db->rep->BeginTransaction(write_options->rep)};
+}
+}
@@ -43,6 +43,13 @@ rocks_cfoptions_t* rocks_cfoptions_create_from_options(rocks_options_t* options)
   return new rocks_cfoptions_t{ColumnFamilyOptions(options->rep)};
 }
 
+// copy, shared_ptr members are shared
+rocks_options_t* rocks_options_copy(rocks_options_t* options) { return new rocks_options_t{options->rep}; }
+
+rocks_dboptions_t* rocks_dboptions_copy(rocks_dboptions_t* options) { return new rocks_dboptions_t{options->rep}; }
+
+rocks_cfoptions_t* rocks_cfoptions_copy(rocks_cfoptions_t* options) { return new rocks_cfoptions_t{options->rep}; }
+
 // cfoptions
 
 void rocks_cfoptions_optimize_for_small_db(rocks_cfoptions_t* opt) { opt->rep.OptimizeForSmallDb(); }
@@ -86,13 +93,11 @@ void rocks_cfoptions_set_compaction_filter_by_trait(rocks_cfoptions_t* opt, void
   // FIXME: will leaks
   opt->rep.compaction_filter = new rocks_compaction_filter_t{filter_trait_obj};
 }
-/*
-void rocks_cfoptions_set_compaction_filter_factory(
-                                                 rocks_options_t* opt,
-rocks_compactionfilterfactory_t* factory) { opt->rep.compaction_filter_factory =
-    std::shared_ptr<CompactionFilterFactory>(factory);
+
+void rocks_cfoptions_set_compaction_filter_factory_by_trait(rocks_cfoptions_t* opt, void* factory_trait_obj) {
+  opt->rep.compaction_filter_factory =
+      std::shared_ptr<CompactionFilterFactory>(new rocks_compaction_filter_factory_t{factory_trait_obj});
 }
-*/
 
 void rocks_cfoptions_set_write_buffer_size(rocks_cfoptions_t* opt, size_t s) { opt->rep.write_buffer_size = s; }
 
@@ -374,21 +379,26 @@ void rocks_dboptions_set_ratelimiter(rocks_dboptions_t* opt, rocks_ratelimiter_t
   }
 }
 
-// sst_file_manager
-/*
-void rocks_dboptions_set_sst_file_manager(rocks_dboptions_t* opt,
-rocks_sst_file_manager_t* manager) { opt->rep.sst_file_manager = manager->rep;
+void rocks_dboptions_set_sst_file_manager(rocks_dboptions_t* opt, rocks_sst_file_manager_t* manager) {
+  if (manager != nullptr) {
+    opt->rep.sst_file_manager = manager->rep;
+  } else {
+    opt->rep.sst_file_manager.reset((SstFileManager*)nullptr);
+  }
 }
-*/
 
 void rocks_dboptions_set_info_log(rocks_dboptions_t* opt, rocks_logger_t* l) {
   if (l) {
     opt->rep.info_log = l->rep;
+    opt->rep.info_log->SetInfoLogLevel(opt->rep.info_log_level);
   }
 }
 
 void rocks_dboptions_set_info_log_level(rocks_dboptions_t* opt, int v) {
   opt->rep.info_log_level = static_cast<InfoLogLevel>(v);
+  if (opt->rep.info_log) {
+    opt->rep.info_log->SetInfoLogLevel(opt->rep.info_log_level);
+  }
 }
 
 void rocks_dboptions_set_max_open_files(rocks_dboptions_t* opt, int n) { opt->rep.max_open_files = n; }
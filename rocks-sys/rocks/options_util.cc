@@ -0,0 +1,63 @@
+#include "rocksdb/utilities/options_util.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+// column family names and options are returned as malloc-ed lists
+static rocks_dboptions_t* rocks_options_from_cf_descs(DBOptions& db_options, std::vector<ColumnFamilyDescriptor>& cf_descs,
+                                                      size_t* num_cfs, char*** cf_names,
+                                                      rocks_cfoptions_t*** cf_options) {
+  *num_cfs = cf_descs.size();
+  *cf_names = static_cast<char**>(malloc(sizeof(char*) * cf_descs.size()));
+  *cf_options = static_cast<rocks_cfoptions_t**>(malloc(sizeof(rocks_cfoptions_t*) * cf_descs.size()));
+  for (size_t i = 0; i < cf_descs.size(); i++) {
+    (*cf_names)[i] = strdup(cf_descs[i].name.c_str());
+    (*cf_options)[i] = new rocks_cfoptions_t{cf_descs[i].options};
+  }
+  return new rocks_dboptions_t{db_options};
+}
+
+extern "C" {
+rocks_dboptions_t* rocks_load_latest_options(const char* dbpath, size_t dbpath_len, rocks_env_t* env,
+                                             unsigned char ignore_unknown_options, size_t* num_cfs, char*** cf_names,
+                                             rocks_cfoptions_t*** cf_options, rocks_status_t** status) {
+  DBOptions db_options;
+  std::vector<ColumnFamilyDescriptor> cf_descs;
+  auto st = LoadLatestOptions(std::string(dbpath, dbpath_len), env->rep, &db_options, &cf_descs,
+                              ignore_unknown_options != 0);
+  if (SaveError(status, std::move(st))) {
+    *num_cfs = 0;
+    return nullptr;
+  }
+  return rocks_options_from_cf_descs(db_options, cf_descs, num_cfs, cf_names, cf_options);
+}
+
+rocks_dboptions_t* rocks_load_options_from_file(const char* options_file_name, size_t options_file_name_len,
+                                                rocks_env_t* env, unsigned char ignore_unknown_options,
+                                                size_t* num_cfs, char*** cf_names, rocks_cfoptions_t*** cf_options,
+                                                rocks_status_t** status) {
+  DBOptions db_options;
+  std::vector<ColumnFamilyDescriptor> cf_descs;
+  auto st = LoadOptionsFromFile(std::string(options_file_name, options_file_name_len), env->rep, &db_options,
+                                &cf_descs, ignore_unknown_options != 0);
+  if (SaveError(status, std::move(st))) {
+    *num_cfs = 0;
+    return nullptr;
+  }
+  return rocks_options_from_cf_descs(db_options, cf_descs, num_cfs, cf_names, cf_options);
+}
+
+// the options are owned by the caller, only the list itself is freed
+void rocks_load_options_cf_options_list_destroy(rocks_cfoptions_t** list) { free(list); }
+
+void rocks_get_latest_options_file_name(const char* dbpath, size_t dbpath_len, rocks_env_t* env,
+                                        void* options_file_name,  // *mut String
+                                        rocks_status_t** status) {
+  std::string name;
+  auto st = GetLatestOptionsFileName(std::string(dbpath, dbpath_len), env->rep, &name);
+  if (!SaveError(status, std::move(st))) {
+    rust_string_assign(options_file_name, name.data(), name.size());
+  }
+}
+}
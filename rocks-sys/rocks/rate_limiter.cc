@@ -17,4 +17,28 @@ rocks_ratelimiter_t* rocks_ratelimiter_create(int64_t rate_bytes_per_sec, int64_
 }
 
 void rocks_ratelimiter_destroy(rocks_ratelimiter_t* limiter) { delete limiter; }
+
+rocks_ratelimiter_t* rocks_ratelimiter_clone(rocks_ratelimiter_t* limiter) {
+  return new rocks_ratelimiter_t{limiter->rep};
+}
+
+void rocks_ratelimiter_set_bytes_per_second(rocks_ratelimiter_t* limiter, int64_t bytes_per_second) {
+  limiter->rep->SetBytesPerSecond(bytes_per_second);
+}
+
+int64_t rocks_ratelimiter_get_single_burst_bytes(rocks_ratelimiter_t* limiter) {
+  return limiter->rep->GetSingleBurstBytes();
+}
+
+int64_t rocks_ratelimiter_get_total_bytes_through(rocks_ratelimiter_t* limiter) {
+  return limiter->rep->GetTotalBytesThrough();
+}
+
+int64_t rocks_ratelimiter_get_total_requests(rocks_ratelimiter_t* limiter) {
+  return limiter->rep->GetTotalRequests();
+}
+
+int64_t rocks_ratelimiter_get_bytes_per_second(rocks_ratelimiter_t* limiter) {
+  return limiter->rep->GetBytesPerSecond();
+}
 }
@@ -36,6 +36,14 @@ extern char rust_compaction_filter_ignore_snapshots(void* f);
 
 extern void rust_compaction_filter_drop(void* f);
 
+/* compaction filter factory */
+extern void* rust_compaction_filter_factory_create_compaction_filter(void* f,
+                                                                    const CompactionFilter::Context* context);
+
+extern const char* rust_compaction_filter_factory_name(void* f);
+
+extern void rust_compaction_filter_factory_drop(void* f);
+
 /* slice transform */
 extern void rust_slice_transform_call(void* t, const Slice* key, char* const* ret, size_t* ret_len);
 
@@ -59,6 +67,11 @@ extern const char* rust_merge_operator_name(void* op);
 
 extern int32_t rust_merge_operator_call_full_merge_v2(void* op, const void* merge_in, void* merge_out);
 
+extern int32_t rust_merge_operator_call_partial_merge_multi(void* op, const Slice* key,
+                                                            const char* const* operand_ptrs,
+                                                            const size_t* operand_lens, size_t num_operands,
+                                                            char** new_value, size_t* new_value_len, Logger* logger);
+
 extern void rust_merge_operator_drop(void* op);
 
 /* comparator */
@@ -160,6 +173,64 @@ extern void rust_compaction_event_listener_on_compaction(
     void* l, int32_t level, const Slice* key, CompactionEventListener::CompactionListenerValueType value_type,
     const Slice* existing_value, uint64_t sn, unsigned char is_new);
 
+// env
+struct cxx_string_vector_t;
+
+extern rocks_status_t* rust_env_new_sequential_file(void* e, const char* fname, size_t fname_len, void** file);
+
+extern rocks_status_t* rust_env_new_random_access_file(void* e, const char* fname, size_t fname_len, void** file);
+
+extern rocks_status_t* rust_env_new_writable_file(void* e, const char* fname, size_t fname_len, void** file);
+
+extern unsigned char rust_env_file_exists(void* e, const char* fname, size_t fname_len);
+
+extern rocks_status_t* rust_env_get_children(void* e, const char* dir, size_t dir_len, cxx_string_vector_t* result);
+
+extern rocks_status_t* rust_env_delete_file(void* e, const char* fname, size_t fname_len);
+
+extern rocks_status_t* rust_env_create_dir(void* e, const char* dirname, size_t dirname_len);
+
+extern rocks_status_t* rust_env_create_dir_if_missing(void* e, const char* dirname, size_t dirname_len);
+
+extern rocks_status_t* rust_env_delete_dir(void* e, const char* dirname, size_t dirname_len);
+
+extern rocks_status_t* rust_env_get_file_size(void* e, const char* fname, size_t fname_len, uint64_t* file_size);
+
+extern rocks_status_t* rust_env_get_file_modification_time(void* e, const char* fname, size_t fname_len,
+                                                           uint64_t* file_mtime);
+
+extern rocks_status_t* rust_env_rename_file(void* e, const char* src, size_t src_len, const char* target,
+                                            size_t target_len);
+
+extern void rust_env_drop(void* e);
+
+extern rocks_status_t* rust_sequential_file_read(void* f, char* scratch, size_t n, size_t* read);
+
+extern rocks_status_t* rust_sequential_file_skip(void* f, uint64_t n);
+
+extern void rust_sequential_file_drop(void* f);
+
+extern rocks_status_t* rust_random_access_file_read(void* f, uint64_t offset, char* scratch, size_t n, size_t* read);
+
+extern void rust_random_access_file_drop(void* f);
+
+extern rocks_status_t* rust_writable_file_append(void* f, const Slice* data);
+
+extern rocks_status_t* rust_writable_file_close(void* f);
+
+extern rocks_status_t* rust_writable_file_flush(void* f);
+
+extern rocks_status_t* rust_writable_file_sync(void* f);
+
+extern void rust_writable_file_drop(void* f);
+
+// logger
+extern void rust_logger_logv(void* l, int level, const char* msg, size_t msg_len);
+
+extern void rust_logger_flush(void* l);
+
+extern void rust_logger_drop(void* l);
+
 #ifdef __cplusplus
 }
 #endif
@@ -12,4 +12,10 @@ void rocks_pinnable_slice_destroy(rocks_pinnable_slice_t* s) { delete s; }
 const char* rocks_pinnable_slice_data(rocks_pinnable_slice_t* s) { return s->rep.data(); }
 
 size_t rocks_pinnable_slice_size(rocks_pinnable_slice_t* s) { return s->rep.size(); }
+
+// PinnableSlice::Reset() leaves data and size as is
+void rocks_pinnable_slice_reset(rocks_pinnable_slice_t* s) {
+  s->rep.Reset();
+  s->rep.clear();
+}
 }
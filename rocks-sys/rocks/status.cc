@@ -11,11 +11,37 @@ rocks_status_t* rocks_status_create() { return new rocks_status_t; }
 
 void rocks_status_destroy(rocks_status_t* s) { delete s; }
 
-// TODO: handle code
 rocks_status_t* rocks_status_create_with_code_and_msg(int code, const char* msg, size_t len) {
   auto ccode = static_cast<Status::Code>(code);
   auto message = Slice(msg, len);
-  return new rocks_status_t{Status::InvalidArgument(message)};
+  switch (ccode) {
+    case Status::kNotFound:
+      return new rocks_status_t{Status::NotFound(message)};
+    case Status::kCorruption:
+      return new rocks_status_t{Status::Corruption(message)};
+    case Status::kNotSupported:
+      return new rocks_status_t{Status::NotSupported(message)};
+    case Status::kIOError:
+      return new rocks_status_t{Status::IOError(message)};
+    case Status::kMergeInProgress:
+      return new rocks_status_t{Status::MergeInProgress(message)};
+    case Status::kIncomplete:
+      return new rocks_status_t{Status::Incomplete(message)};
+    case Status::kShutdownInProgress:
+      return new rocks_status_t{Status::ShutdownInProgress(message)};
+    case Status::kTimedOut:
+      return new rocks_status_t{Status::TimedOut(message)};
+    case Status::kAborted:
+      return new rocks_status_t{Status::Aborted(message)};
+    case Status::kBusy:
+      return new rocks_status_t{Status::Busy(message)};
+    case Status::kExpired:
+      return new rocks_status_t{Status::Expired(message)};
+    case Status::kTryAgain:
+      return new rocks_status_t{Status::TryAgain(message)};
+    default:
+      return new rocks_status_t{Status::InvalidArgument(message)};
+  }
 }
 
 int rocks_status_code(rocks_status_t* s) { return s->rep.code(); }
@@ -60,6 +60,9 @@ uint64_t rocks_table_props_get_num_data_blocks(rocks_table_props_t* prop) { retu
 uint64_t rocks_table_props_get_num_entries(rocks_table_props_t* prop) { return prop->rep->num_entries; }
 uint64_t rocks_table_props_get_format_version(rocks_table_props_t* prop) { return prop->rep->format_version; }
 uint64_t rocks_table_props_get_fixed_key_len(rocks_table_props_t* prop) { return prop->rep->fixed_key_len; }
+uint64_t rocks_table_props_get_num_deletions(rocks_table_props_t* prop) {
+  return GetDeletedKeys(prop->rep->user_collected_properties);
+}
 uint32_t rocks_table_props_get_column_family_id(rocks_table_props_t* prop) { return prop->rep->column_family_id; }
 const char* rocks_table_props_get_column_family_name(rocks_table_props_t* prop, size_t* len) {
   *len = prop->rep->column_family_name.size();
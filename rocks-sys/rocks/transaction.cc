@@ -0,0 +1,58 @@
+#include "rocksdb/utilities/transaction.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+extern "C" {
+void rocks_transaction_destroy(rocks_transaction_t* txn) {
+  delete txn->rep;
+  delete txn;
+}
+
+void rocks_transaction_set_lock_timeout(rocks_transaction_t* txn, int64_t timeout) {
+  txn->rep->SetLockTimeout(timeout);
+}
+
+void rocks_transaction_commit(rocks_transaction_t* txn, rocks_status_t** status) {
+  SaveError(status, txn->rep->Commit());
+}
+
+void rocks_transaction_rollback(rocks_transaction_t* txn, rocks_status_t** status) {
+  SaveError(status, txn->rep->Rollback());
+}
+
+void rocks_transaction_get(rocks_transaction_t* txn, const rocks_readoptions_t* options, const char* key,
+                           size_t klen,
+                           void* value,  // *mut Vec<u8>
+                           rocks_status_t** status) {
+  std::string val;
+  if (!SaveError(status, txn->rep->Get(options->rep, Slice(key, klen), &val))) {
+    rust_vec_u8_assign(value, val.data(), val.size());
+  }
+}
+
+void rocks_transaction_get_for_update(rocks_transaction_t* txn, const rocks_readoptions_t* options, const char* key,
+                                      size_t klen,
+                                      void* value,  // *mut Vec<u8>
+                                      rocks_status_t** status) {
+  std::string val;
+  if (!SaveError(status, txn->rep->GetForUpdate(options->rep, Slice(key, klen), &val))) {
+    rust_vec_u8_assign(value, val.data(), val.size());
+  }
+}
+
+void rocks_transaction_put(rocks_transaction_t* txn, const char* key, size_t klen, const char* val, size_t vlen,
+                           rocks_status_t** status) {
+  SaveError(status, txn->rep->Put(Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocks_transaction_merge(rocks_transaction_t* txn, const char* key, size_t klen, const char* val, size_t vlen,
+                             rocks_status_t** status) {
+  SaveError(status, txn->rep->Merge(Slice(key, klen), Slice(val, vlen)));
+}
+
+void rocks_transaction_delete(rocks_transaction_t* txn, const char* key, size_t klen, rocks_status_t** status) {
+  SaveError(status, txn->rep->Delete(Slice(key, klen)));
+}
+}
@@ -0,0 +1,56 @@
+#include "rocksdb/utilities/transaction_db.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+extern "C" {
+// rocks_transactiondb_options_t
+rocks_transactiondb_options_t* rocks_transactiondb_options_create() { return new rocks_transactiondb_options_t; }
+
+void rocks_transactiondb_options_destroy(rocks_transactiondb_options_t* options) { delete options; }
+
+void rocks_transactiondb_options_set_max_num_locks(rocks_transactiondb_options_t* options, int64_t v) {
+  options->rep.max_num_locks = v;
+}
+
+void rocks_transactiondb_options_set_num_stripes(rocks_transactiondb_options_t* options, size_t v) {
+  options->rep.num_stripes = v;
+}
+
+void rocks_transactiondb_options_set_transaction_lock_timeout(rocks_transactiondb_options_t* options, int64_t v) {
+  options->rep.transaction_lock_timeout = v;
+}
+
+void rocks_transactiondb_options_set_default_lock_timeout(rocks_transactiondb_options_t* options, int64_t v) {
+  options->rep.default_lock_timeout = v;
+}
+
+// rocks_transactiondb_t
+rocks_transactiondb_t* rocks_transactiondb_open(const rocks_options_t* options,
+                                                const rocks_transactiondb_options_t* txn_db_options, const char* name,
+                                                size_t name_len, rocks_status_t** status) {
+  TransactionDB* txn_db = nullptr;
+  if (SaveError(status,
+                TransactionDB::Open(options->rep, txn_db_options->rep, std::string(name, name_len), &txn_db))) {
+    return nullptr;
+  }
+  return new rocks_transactiondb_t{txn_db};
+}
+
+void rocks_transactiondb_close(rocks_transactiondb_t* txn_db) {
+  delete txn_db->rep;
+  delete txn_db;
+}
+
+// TransactionDB is a StackableDB, the returned wrapper does not own it,
+// release with rocks_transactiondb_close_base_db
+rocks_db_t* rocks_transactiondb_get_base_db(rocks_transactiondb_t* txn_db) { return new rocks_db_t{txn_db->rep}; }
+
+void rocks_transactiondb_close_base_db(rocks_db_t* base_db) { delete base_db; }
+
+rocks_transaction_t* rocks_transactiondb_begin_transaction(rocks_transactiondb_t* txn_db,
+                                                           const rocks_writeoptions_t* write_options) {
+  return new rocks_transaction_t{txn_db->rep->BeginTransaction(write_options->rep)};
+}
+}
@@ -50,4 +50,6 @@ size_t cxx_string_vector_size(cxx_string_vector_t* v) { return v->rep.size(); }
 const char* cxx_string_vector_nth(cxx_string_vector_t* v, size_t index) { return v->rep[index].data(); }
 
 size_t cxx_string_vector_nth_size(cxx_string_vector_t* v, size_t index) { return v->rep[index].size(); }
+
+void cxx_string_vector_push_back(cxx_string_vector_t* v, const char* p, size_t len) { v->rep.emplace_back(p, len); }
 }
@@ -184,12 +184,24 @@ const char* rocks_writebatch_data(rocks_writebatch_t* b, size_t* size) {
   return b->rep->Data().c_str();
 }
 
+size_t rocks_writebatch_get_data_size(rocks_writebatch_t* b) { return b->rep->GetDataSize(); }
+
 void rocks_writebatch_set_save_point(rocks_writebatch_t* b) { b->rep->SetSavePoint(); }
 
 void rocks_writebatch_rollback_to_save_point(rocks_writebatch_t* b, rocks_status_t** status) {
   SaveError(status, std::move(b->rep->RollbackToSavePoint()));
 }
 
+void rocks_writebatch_mark_wal_termination_point(rocks_writebatch_t* b) { b->rep->MarkWalTerminationPoint(); }
+
+void rocks_writebatch_get_wal_termination_point(rocks_writebatch_t* b, size_t* size, int* count,
+                                                uint32_t* content_flags) {
+  auto& point = b->rep->GetWalTerminationPoint();
+  *size = point.size;
+  *count = point.count;
+  *content_flags = point.content_flags;
+}
+
 rocks_writebatch_t* rocks_writebatch_copy(rocks_writebatch_t* b) {
   return new rocks_writebatch_t{std::unique_ptr<WriteBatch>(new WriteBatch(*b->rep))};
 }
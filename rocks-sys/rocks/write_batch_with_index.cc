@@ -0,0 +1,102 @@
+#include "rocksdb/utilities/write_batch_with_index.h"
+
+#include "rocks/ctypes.hpp"
+
+using namespace rocksdb;
+
+extern "C" {
+rocks_writebatch_with_index_t* rocks_writebatch_with_index_create(size_t reserved_bytes, unsigned char overwrite_key) {
+  return new rocks_writebatch_with_index_t{std::unique_ptr<WriteBatchWithIndex>(
+      new WriteBatchWithIndex(BytewiseComparator(), reserved_bytes, overwrite_key))};
+}
+
+void rocks_writebatch_with_index_destroy(rocks_writebatch_with_index_t* b) { delete b; }
+
+void rocks_writebatch_with_index_clear(rocks_writebatch_with_index_t* b) { b->rep->Clear(); }
+
+int rocks_writebatch_with_index_count(rocks_writebatch_with_index_t* b) { return b->rep->GetWriteBatch()->Count(); }
+
+void rocks_writebatch_with_index_put(rocks_writebatch_with_index_t* b, const char* key, size_t klen, const char* val,
+                                     size_t vlen) {
+  b->rep->Put(Slice(key, klen), Slice(val, vlen));
+}
+
+void rocks_writebatch_with_index_put_cf(rocks_writebatch_with_index_t* b,
+                                        rocks_column_family_handle_t* column_family, const char* key, size_t klen,
+                                        const char* val, size_t vlen) {
+  b->rep->Put(column_family->rep, Slice(key, klen), Slice(val, vlen));
+}
+
+void rocks_writebatch_with_index_merge(rocks_writebatch_with_index_t* b, const char* key, size_t klen,
+                                       const char* val, size_t vlen) {
+  b->rep->Merge(Slice(key, klen), Slice(val, vlen));
+}
+
+void rocks_writebatch_with_index_merge_cf(rocks_writebatch_with_index_t* b,
+                                          rocks_column_family_handle_t* column_family, const char* key, size_t klen,
+                                          const char* val, size_t vlen) {
+  b->rep->Merge(column_family->rep, Slice(key, klen), Slice(val, vlen));
+}
+
+void rocks_writebatch_with_index_delete(rocks_writebatch_with_index_t* b, const char* key, size_t klen) {
+  b->rep->Delete(Slice(key, klen));
+}
+
+void rocks_writebatch_with_index_delete_cf(rocks_writebatch_with_index_t* b,
+                                           rocks_column_family_handle_t* column_family, const char* key,
+                                           size_t klen) {
+  b->rep->Delete(column_family->rep, Slice(key, klen));
+}
+
+void rocks_writebatch_with_index_single_delete(rocks_writebatch_with_index_t* b, const char* key, size_t klen) {
+  b->rep->SingleDelete(Slice(key, klen));
+}
+
+void rocks_writebatch_with_index_single_delete_cf(rocks_writebatch_with_index_t* b,
+                                                  rocks_column_family_handle_t* column_family, const char* key,
+                                                  size_t klen) {
+  b->rep->SingleDelete(column_family->rep, Slice(key, klen));
+}
+
+rocks_writebatch_t* rocks_writebatch_with_index_get_writebatch(rocks_writebatch_with_index_t* b) {
+  return new rocks_writebatch_t{std::unique_ptr<WriteBatch>(new WriteBatch(*b->rep->GetWriteBatch()))};
+}
+
+void rocks_writebatch_with_index_get_from_batch(rocks_writebatch_with_index_t* b, const rocks_dboptions_t* options,
+                                                const char* key, size_t klen,
+                                                void* value,  // *mut Vec<u8>
+                                                rocks_status_t** status) {
+  std::string val;
+  if (!SaveError(status, b->rep->GetFromBatch(options->rep, Slice(key, klen), &val))) {
+    rust_vec_u8_assign(value, val.data(), val.size());
+  }
+}
+
+void rocks_writebatch_with_index_get_from_batch_cf(rocks_writebatch_with_index_t* b,
+                                                   rocks_column_family_handle_t* column_family,
+                                                   const rocks_dboptions_t* options, const char* key, size_t klen,
+                                                   void* value,  // *mut Vec<u8>
+                                                   rocks_status_t** status) {
+  std::string val;
+  if (!SaveError(status, b->rep->GetFromBatch(column_family->rep, options->rep, Slice(key, klen), &val))) {
+    rust_vec_u8_assign(value, val.data(), val.size());
+  }
+}
+
+void rocks_writebatch_with_index_get_from_batch_and_db(rocks_writebatch_with_index_t* b, rocks_db_t* db,
+                                                       const rocks_readoptions_t* options, const char* key,
+                                                       size_t klen, rocks_pinnable_slice_t* value,
+                                                       rocks_status_t** status) {
+  SaveError(status, b->rep->GetFromBatchAndDB(db->rep, options->rep, db->rep->DefaultColumnFamily(),
+                                              Slice(key, klen), &value->rep));
+}
+
+void rocks_writebatch_with_index_get_from_batch_and_db_cf(rocks_writebatch_with_index_t* b, rocks_db_t* db,
+                                                          const rocks_readoptions_t* options,
+                                                          rocks_column_family_handle_t* column_family,
+                                                          const char* key, size_t klen,
+                                                          rocks_pinnable_slice_t* value, rocks_status_t** status) {
+  SaveError(status,
+            b->rep->GetFromBatchAndDB(db->rep, options->rep, column_family->rep, Slice(key, klen), &value->rep));
+}
+}
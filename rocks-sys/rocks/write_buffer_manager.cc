@@ -11,6 +11,12 @@ rocks_write_buffer_manager_t* rocks_write_buffer_manager_create(size_t buffer_si
   return manager;
 }
 
+rocks_write_buffer_manager_t* rocks_write_buffer_manager_create_with_cache(size_t buffer_size, rocks_cache_t* cache) {
+  auto manager = new rocks_write_buffer_manager_t;
+  manager->rep.reset(new WriteBufferManager(buffer_size, cache->rep));
+  return manager;
+}
+
 void rocks_write_buffer_manager_destroy(rocks_write_buffer_manager_t* manager) { delete manager; }
 
 unsigned char rocks_write_buffer_manager_enabled(rocks_write_buffer_manager_t* manager) {
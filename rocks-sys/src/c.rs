@@ -53,6 +53,9 @@ pub struct rocks_status_t([u8; 0]);
 pub struct rocks_ratelimiter_t([u8; 0]);
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
+pub struct rocks_sst_file_manager_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
 pub struct rocks_envoptions_t([u8; 0]);
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -206,6 +209,33 @@ pub struct rocks_external_file_ingestion_info_t([u8; 0]);
 pub struct rocks_thread_status_t([u8; 0]);
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
+pub struct rocks_checkpoint_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_backup_engine_options_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_backup_engine_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_backup_infos_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_writebatch_with_index_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_transaction_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_optimistic_transaction_db_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_transactiondb_options_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct rocks_transactiondb_t([u8; 0]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
 pub struct cxx_string_vector_t([u8; 0]);
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -247,6 +277,9 @@ extern "C" {
 extern "C" {
     pub fn rocks_pinnable_slice_size(s: *mut rocks_pinnable_slice_t) -> usize;
 }
+extern "C" {
+    pub fn rocks_pinnable_slice_reset(s: *mut rocks_pinnable_slice_t);
+}
 extern "C" {
     pub fn rocks_options_create() -> *mut rocks_options_t;
 }
@@ -280,6 +313,18 @@ extern "C" {
     pub fn rocks_cfoptions_create_from_options(options: *mut rocks_options_t)
      -> *mut rocks_cfoptions_t;
 }
+extern "C" {
+    pub fn rocks_options_copy(options: *mut rocks_options_t)
+     -> *mut rocks_options_t;
+}
+extern "C" {
+    pub fn rocks_dboptions_copy(options: *mut rocks_dboptions_t)
+     -> *mut rocks_dboptions_t;
+}
+extern "C" {
+    pub fn rocks_cfoptions_copy(options: *mut rocks_cfoptions_t)
+     -> *mut rocks_cfoptions_t;
+}
 extern "C" {
     pub fn rocks_cfoptions_optimize_for_small_db(opt: *mut rocks_cfoptions_t);
 }
@@ -325,6 +370,12 @@ extern "C" {
                                                           filter_trait_obj:
                                                               *mut ::std::os::raw::c_void);
 }
+extern "C" {
+    pub fn rocks_cfoptions_set_compaction_filter_factory_by_trait(opt:
+                                                                      *mut rocks_cfoptions_t,
+                                                                  factory_trait_obj:
+                                                                      *mut ::std::os::raw::c_void);
+}
 extern "C" {
     pub fn rocks_cfoptions_set_bitwise_comparator(opt: *mut rocks_cfoptions_t,
                                                   reversed:
@@ -663,6 +714,11 @@ extern "C" {
     pub fn rocks_dboptions_set_ratelimiter(opt: *mut rocks_dboptions_t,
                                            limiter: *mut rocks_ratelimiter_t);
 }
+extern "C" {
+    pub fn rocks_dboptions_set_sst_file_manager(opt: *mut rocks_dboptions_t,
+                                                manager:
+                                                    *mut rocks_sst_file_manager_t);
+}
 extern "C" {
     pub fn rocks_dboptions_set_info_log(opt: *mut rocks_dboptions_t,
                                         l: *mut rocks_logger_t);
@@ -1293,6 +1349,11 @@ extern "C" {
                                                  status:
                                                      *mut *mut rocks_status_t);
 }
+extern "C" {
+    pub fn rocks_column_family_handle_clone(handle:
+                                                *mut rocks_column_family_handle_t)
+     -> *mut rocks_column_family_handle_t;
+}
 extern "C" {
     pub fn rocks_column_family_handle_destroy(handle:
                                                   *mut rocks_column_family_handle_t);
@@ -1504,6 +1565,10 @@ extern "C" {
                                                 value: *mut u64)
      -> ::std::os::raw::c_uchar;
 }
+extern "C" {
+    pub fn rocks_db_reset_stats(db: *mut rocks_db_t,
+                                status: *mut *mut rocks_status_t);
+}
 extern "C" {
     pub fn rocks_db_compact_range(db: *mut rocks_db_t,
                                   start_key: *const ::std::os::raw::c_char,
@@ -1565,15 +1630,17 @@ extern "C" {
                                    status: *mut *mut rocks_status_t);
 }
 extern "C" {
-    pub fn rocks_db_compact_files(db: *mut rocks_db_t,
-                                  opt: *mut rocks_compaction_options_t,
-                                  num_files: usize,
-                                  file_names:
-                                      *const *const ::std::os::raw::c_char,
-                                  file_name_lens: *const usize,
-                                  output_level: ::std::os::raw::c_int,
-                                  output_path_id: ::std::os::raw::c_int,
-                                  status: *mut *mut rocks_status_t);
+    pub fn rocks_db_compact_files_cf(db: *mut rocks_db_t,
+                                     opt: *mut rocks_compaction_options_t,
+                                     column_family:
+                                         *mut rocks_column_family_handle_t,
+                                     num_files: usize,
+                                     file_names:
+                                         *const *const ::std::os::raw::c_char,
+                                     file_name_lens: *const usize,
+                                     output_level: ::std::os::raw::c_int,
+                                     output_path_id: ::std::os::raw::c_int,
+                                     status: *mut *mut rocks_status_t);
 }
 extern "C" {
     pub fn rocks_db_pause_background_work(db: *mut rocks_db_t,
@@ -1662,6 +1729,10 @@ extern "C" {
                              column_family: *mut rocks_column_family_handle_t,
                              status: *mut *mut rocks_status_t);
 }
+extern "C" {
+    pub fn rocks_db_flush_wal(db: *mut rocks_db_t, sync: ::std::os::raw::c_uchar,
+                              status: *mut *mut rocks_status_t);
+}
 extern "C" {
     pub fn rocks_db_sync_wal(db: *mut rocks_db_t,
                              status: *mut *mut rocks_status_t);
@@ -1738,6 +1809,16 @@ extern "C" {
                                     identity: *mut ::std::os::raw::c_void,
                                     status: *mut *mut rocks_status_t);
 }
+extern "C" {
+    pub fn rocks_db_get_options(db: *mut rocks_db_t,
+                                column_family:
+                                    *mut rocks_column_family_handle_t)
+     -> *mut rocks_cfoptions_t;
+}
+extern "C" {
+    pub fn rocks_db_get_db_options(db: *mut rocks_db_t)
+     -> *mut rocks_dboptions_t;
+}
 extern "C" {
     pub fn rocks_db_get_properties_of_all_tables(db: *mut rocks_db_t,
                                                  cf:
@@ -1781,6 +1862,81 @@ extern "C" {
 extern "C" {
     pub fn rocks_ratelimiter_destroy(limiter: *mut rocks_ratelimiter_t);
 }
+extern "C" {
+    pub fn rocks_ratelimiter_clone(limiter: *mut rocks_ratelimiter_t)
+     -> *mut rocks_ratelimiter_t;
+}
+extern "C" {
+    pub fn rocks_ratelimiter_set_bytes_per_second(limiter:
+                                                      *mut rocks_ratelimiter_t,
+                                                  bytes_per_second: i64);
+}
+extern "C" {
+    pub fn rocks_ratelimiter_get_single_burst_bytes(limiter:
+                                                        *mut rocks_ratelimiter_t)
+     -> i64;
+}
+extern "C" {
+    pub fn rocks_ratelimiter_get_total_bytes_through(limiter:
+                                                         *mut rocks_ratelimiter_t)
+     -> i64;
+}
+extern "C" {
+    pub fn rocks_ratelimiter_get_total_requests(limiter:
+                                                    *mut rocks_ratelimiter_t)
+     -> i64;
+}
+extern "C" {
+    pub fn rocks_ratelimiter_get_bytes_per_second(limiter:
+                                                      *mut rocks_ratelimiter_t)
+     -> i64;
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_create(env: *mut rocks_env_t,
+                                         info_log: *mut rocks_logger_t,
+                                         trash_dir: *const ::std::os::raw::c_char,
+                                         trash_dir_len: usize,
+                                         rate_bytes_per_sec: i64,
+                                         delete_existing_trash:
+                                             ::std::os::raw::c_uchar,
+                                         status: *mut *mut rocks_status_t)
+     -> *mut rocks_sst_file_manager_t;
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_destroy(manager:
+                                              *mut rocks_sst_file_manager_t);
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_clone(manager: *mut rocks_sst_file_manager_t)
+     -> *mut rocks_sst_file_manager_t;
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_set_max_allowed_space_usage(manager:
+                                                                  *mut rocks_sst_file_manager_t,
+                                                              max_allowed_space:
+                                                                  u64);
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_is_max_allowed_space_reached(manager:
+                                                                   *mut rocks_sst_file_manager_t)
+     -> ::std::os::raw::c_uchar;
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_get_total_size(manager:
+                                                     *mut rocks_sst_file_manager_t)
+     -> u64;
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_get_delete_rate_bytes_per_second(manager:
+                                                                       *mut rocks_sst_file_manager_t)
+     -> i64;
+}
+extern "C" {
+    pub fn rocks_sst_file_manager_set_delete_rate_bytes_per_second(manager:
+                                                                       *mut rocks_sst_file_manager_t,
+                                                                   delete_rate:
+                                                                       i64);
+}
 extern "C" {
     pub fn rocks_create_default_env() -> *mut rocks_env_t;
 }
@@ -1790,6 +1946,10 @@ extern "C" {
 extern "C" {
     pub fn rocks_create_timed_env() -> *mut rocks_env_t;
 }
+extern "C" {
+    pub fn rocks_create_env_by_trait(fs_trait_obj: *mut ::std::os::raw::c_void)
+     -> *mut rocks_env_t;
+}
 extern "C" {
     pub fn rocks_env_destroy(env: *mut rocks_env_t);
 }
@@ -1929,6 +2089,11 @@ extern "C" {
                                                                   *mut rocks_envoptions_t,
                                                               val: usize);
 }
+extern "C" {
+    pub fn rocks_logger_create_by_trait(logger_trait_obj:
+                                            *mut ::std::os::raw::c_void)
+     -> *mut rocks_logger_t;
+}
 extern "C" {
     pub fn rocks_logger_destroy(logger: *mut rocks_logger_t);
 }
@@ -1971,6 +2136,11 @@ extern "C" {
     pub fn rocks_writebatch_create_with_reserved_bytes(size: usize)
      -> *mut rocks_writebatch_t;
 }
+extern "C" {
+    pub fn rocks_writebatch_create_from(rep: *const ::std::os::raw::c_char,
+                                        size: usize)
+     -> *mut rocks_writebatch_t;
+}
 extern "C" {
     pub fn rocks_writebatch_destroy(b: *mut rocks_writebatch_t);
 }
@@ -2160,6 +2330,10 @@ extern "C" {
     pub fn rocks_writebatch_data(b: *mut rocks_writebatch_t, size: *mut usize)
      -> *const ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn rocks_writebatch_get_data_size(b: *mut rocks_writebatch_t)
+     -> usize;
+}
 extern "C" {
     pub fn rocks_writebatch_set_save_point(b: *mut rocks_writebatch_t);
 }
@@ -2168,6 +2342,18 @@ extern "C" {
                                                    status:
                                                        *mut *mut rocks_status_t);
 }
+extern "C" {
+    pub fn rocks_writebatch_mark_wal_termination_point(b:
+                                                           *mut rocks_writebatch_t);
+}
+extern "C" {
+    pub fn rocks_writebatch_get_wal_termination_point(b:
+                                                          *mut rocks_writebatch_t,
+                                                      size: *mut usize,
+                                                      count:
+                                                          *mut ::std::os::raw::c_int,
+                                                      content_flags: *mut u32);
+}
 extern "C" {
     pub fn rocks_writebatch_has_put(b: *mut rocks_writebatch_t)
      -> ::std::os::raw::c_uchar;
@@ -2465,6 +2651,16 @@ extern "C" {
     pub fn rocks_iter_value(iter: *const rocks_iterator_t, vlen: *mut usize)
      -> *const ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn rocks_iter_copy_key(iter: *const rocks_iterator_t,
+                               klen: *mut usize)
+     -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn rocks_iter_copy_value(iter: *const rocks_iterator_t,
+                                 vlen: *mut usize)
+     -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn rocks_iter_get_status(iter: *const rocks_iterator_t,
                                  status: *mut *mut rocks_status_t);
@@ -2508,6 +2704,10 @@ extern "C" {
 extern "C" {
     pub fn rocks_cache_destroy(cache: *mut rocks_cache_t);
 }
+extern "C" {
+    pub fn rocks_cache_clone(cache: *mut rocks_cache_t)
+     -> *mut rocks_cache_t;
+}
 extern "C" {
     pub fn rocks_cache_set_capacity(cache: *mut rocks_cache_t,
                                     capacity: usize);
@@ -2525,6 +2725,19 @@ extern "C" {
     pub fn rocks_cache_name(cache: *mut rocks_cache_t)
      -> *const ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn rocks_cache_insert(cache: *mut rocks_cache_t,
+                              key: *const ::std::os::raw::c_char,
+                              key_len: usize,
+                              val: *const ::std::os::raw::c_char,
+                              val_len: usize, charge: usize,
+                              status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_cache_erase(cache: *mut rocks_cache_t,
+                             key: *const ::std::os::raw::c_char,
+                             key_len: usize);
+}
 extern "C" {
     pub fn rocks_new_persistent_cache(env: *const rocks_env_t,
                                       path: *const ::std::os::raw::c_char,
@@ -3127,6 +3340,18 @@ extern "C" {
                                           end_len: usize,
                                           status: *mut *mut rocks_status_t);
 }
+extern "C" {
+    pub fn rocks_db_suggest_compact_range(db: *mut rocks_db_t,
+                                          column_family:
+                                              *mut rocks_column_family_handle_t,
+                                          begin_ptr:
+                                              *const ::std::os::raw::c_char,
+                                          begin_len: usize,
+                                          end_ptr:
+                                              *const ::std::os::raw::c_char,
+                                          end_len: usize,
+                                          status: *mut *mut rocks_status_t);
+}
 extern "C" {
     pub fn rocks_get_string_from_dboptions(opts: *mut rocks_dboptions_t)
      -> *mut cxx_string_t;
@@ -3135,6 +3360,24 @@ extern "C" {
     pub fn rocks_get_string_from_cfoptions(opts: *mut rocks_cfoptions_t)
      -> *mut cxx_string_t;
 }
+extern "C" {
+    pub fn rocks_get_cfoptions_from_string(base_options:
+                                               *mut rocks_cfoptions_t,
+                                           opts_str:
+                                               *const ::std::os::raw::c_char,
+                                           opts_len: usize,
+                                           status: *mut *mut rocks_status_t)
+     -> *mut rocks_cfoptions_t;
+}
+extern "C" {
+    pub fn rocks_get_dboptions_from_string(base_options:
+                                               *mut rocks_dboptions_t,
+                                           opts_str:
+                                               *const ::std::os::raw::c_char,
+                                           opts_len: usize,
+                                           status: *mut *mut rocks_status_t)
+     -> *mut rocks_dboptions_t;
+}
 extern "C" {
     pub fn rocks_table_props_collection_destroy(coll:
                                                     *mut rocks_table_props_collection_t);
@@ -3223,6 +3466,10 @@ extern "C" {
     pub fn rocks_table_props_get_fixed_key_len(prop: *mut rocks_table_props_t)
      -> u64;
 }
+extern "C" {
+    pub fn rocks_table_props_get_num_deletions(prop: *mut rocks_table_props_t)
+     -> u64;
+}
 extern "C" {
     pub fn rocks_table_props_get_column_family_id(prop:
                                                       *mut rocks_table_props_t)
@@ -3334,6 +3581,12 @@ extern "C" {
     pub fn rocks_write_buffer_manager_create(buffer_size: usize)
      -> *mut rocks_write_buffer_manager_t;
 }
+extern "C" {
+    pub fn rocks_write_buffer_manager_create_with_cache(buffer_size: usize,
+                                                        cache:
+                                                            *mut rocks_cache_t)
+     -> *mut rocks_write_buffer_manager_t;
+}
 extern "C" {
     pub fn rocks_write_buffer_manager_destroy(manager:
                                                   *mut rocks_write_buffer_manager_t);
@@ -3818,6 +4071,484 @@ extern "C" {
                                                   *const rocks_thread_status_t)
      -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn rocks_checkpoint_create(db: *mut rocks_db_t,
+                                   status: *mut *mut rocks_status_t)
+     -> *mut rocks_checkpoint_t;
+}
+extern "C" {
+    pub fn rocks_checkpoint_destroy(checkpoint: *mut rocks_checkpoint_t);
+}
+extern "C" {
+    pub fn rocks_checkpoint_create_checkpoint(checkpoint:
+                                                  *mut rocks_checkpoint_t,
+                                              checkpoint_dir:
+                                                  *const ::std::os::raw::c_char,
+                                              checkpoint_dir_len: usize,
+                                              log_size_for_flush: u64,
+                                              status:
+                                                  *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_load_latest_options(dbpath: *const ::std::os::raw::c_char,
+                                     dbpath_len: usize, env: *mut rocks_env_t,
+                                     ignore_unknown_options:
+                                         ::std::os::raw::c_uchar,
+                                     num_cfs: *mut usize,
+                                     cf_names:
+                                         *mut *mut *mut ::std::os::raw::c_char,
+                                     cf_options:
+                                         *mut *mut *mut rocks_cfoptions_t,
+                                     status: *mut *mut rocks_status_t)
+     -> *mut rocks_dboptions_t;
+}
+extern "C" {
+    pub fn rocks_load_options_from_file(options_file_name:
+                                            *const ::std::os::raw::c_char,
+                                        options_file_name_len: usize,
+                                        env: *mut rocks_env_t,
+                                        ignore_unknown_options:
+                                            ::std::os::raw::c_uchar,
+                                        num_cfs: *mut usize,
+                                        cf_names:
+                                            *mut *mut *mut ::std::os::raw::c_char,
+                                        cf_options:
+                                            *mut *mut *mut rocks_cfoptions_t,
+                                        status: *mut *mut rocks_status_t)
+     -> *mut rocks_dboptions_t;
+}
+extern "C" {
+    pub fn rocks_load_options_cf_options_list_destroy(list:
+                                                          *mut *mut rocks_cfoptions_t);
+}
+extern "C" {
+    pub fn rocks_get_latest_options_file_name(dbpath:
+                                                  *const ::std::os::raw::c_char,
+                                              dbpath_len: usize,
+                                              env: *mut rocks_env_t,
+                                              options_file_name:
+                                                  *mut ::std::os::raw::c_void,
+                                              status:
+                                                  *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_create(backup_dir:
+                                                  *const ::std::os::raw::c_char,
+                                              backup_dir_len: usize)
+     -> *mut rocks_backup_engine_options_t;
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_destroy(options:
+                                                   *mut rocks_backup_engine_options_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_set_share_table_files(options:
+                                                                 *mut rocks_backup_engine_options_t,
+                                                             v: ::std::os::raw::c_uchar);
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_set_sync(options:
+                                                    *mut rocks_backup_engine_options_t,
+                                                v: ::std::os::raw::c_uchar);
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_set_destroy_old_data(options:
+                                                                *mut rocks_backup_engine_options_t,
+                                                            v: ::std::os::raw::c_uchar);
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_set_backup_log_files(options:
+                                                                *mut rocks_backup_engine_options_t,
+                                                            v: ::std::os::raw::c_uchar);
+}
+extern "C" {
+    pub fn rocks_backup_engine_options_set_max_background_operations(options:
+                                                                         *mut rocks_backup_engine_options_t,
+                                                                     v: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn rocks_backup_engine_open(options:
+                                        *const rocks_backup_engine_options_t,
+                                    env: *mut rocks_env_t,
+                                    status: *mut *mut rocks_status_t)
+     -> *mut rocks_backup_engine_t;
+}
+extern "C" {
+    pub fn rocks_backup_engine_close(engine: *mut rocks_backup_engine_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_create_new_backup(engine:
+                                                     *mut rocks_backup_engine_t,
+                                                 db: *mut rocks_db_t,
+                                                 flush_before_backup:
+                                                     ::std::os::raw::c_uchar,
+                                                 status:
+                                                     *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_purge_old_backups(engine:
+                                                     *mut rocks_backup_engine_t,
+                                                 num_backups_to_keep: u32,
+                                                 status:
+                                                     *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_delete_backup(engine:
+                                                 *mut rocks_backup_engine_t,
+                                             backup_id: u32,
+                                             status:
+                                                 *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_verify_backup(engine:
+                                                 *mut rocks_backup_engine_t,
+                                             backup_id: u32,
+                                             status:
+                                                 *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_backup_engine_get_backup_info(engine:
+                                                   *mut rocks_backup_engine_t)
+     -> *mut rocks_backup_infos_t;
+}
+extern "C" {
+    pub fn rocks_backup_engine_restore_db_from_latest_backup(engine:
+                                                                 *mut rocks_backup_engine_t,
+                                                             db_dir:
+                                                                 *const ::std::os::raw::c_char,
+                                                             db_dir_len:
+                                                                 usize,
+                                                             wal_dir:
+                                                                 *const ::std::os::raw::c_char,
+                                                             wal_dir_len:
+                                                                 usize,
+                                                             keep_log_files:
+                                                                 ::std::os::raw::c_uchar,
+                                                             status:
+                                                                 *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_backup_infos_destroy(infos: *mut rocks_backup_infos_t);
+}
+extern "C" {
+    pub fn rocks_backup_infos_size(infos: *mut rocks_backup_infos_t)
+     -> usize;
+}
+extern "C" {
+    pub fn rocks_backup_infos_nth_backup_id(infos: *mut rocks_backup_infos_t,
+                                            nth: usize) -> u32;
+}
+extern "C" {
+    pub fn rocks_backup_infos_nth_timestamp(infos: *mut rocks_backup_infos_t,
+                                            nth: usize) -> i64;
+}
+extern "C" {
+    pub fn rocks_backup_infos_nth_size(infos: *mut rocks_backup_infos_t,
+                                       nth: usize) -> u64;
+}
+extern "C" {
+    pub fn rocks_backup_infos_nth_number_files(infos:
+                                                   *mut rocks_backup_infos_t,
+                                               nth: usize) -> u32;
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_create(reserved_bytes: usize,
+                                              overwrite_key: ::std::os::raw::c_uchar)
+     -> *mut rocks_writebatch_with_index_t;
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_destroy(b:
+                                                   *mut rocks_writebatch_with_index_t);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_clear(b:
+                                                 *mut rocks_writebatch_with_index_t);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_count(b:
+                                                 *mut rocks_writebatch_with_index_t)
+     -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_put(b:
+                                               *mut rocks_writebatch_with_index_t,
+                                           key: *const ::std::os::raw::c_char,
+                                           klen: usize,
+                                           val: *const ::std::os::raw::c_char,
+                                           vlen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_put_cf(b:
+                                                  *mut rocks_writebatch_with_index_t,
+                                              column_family:
+                                                  *mut rocks_column_family_handle_t,
+                                              key:
+                                                  *const ::std::os::raw::c_char,
+                                              klen: usize,
+                                              val:
+                                                  *const ::std::os::raw::c_char,
+                                              vlen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_merge(b:
+                                                 *mut rocks_writebatch_with_index_t,
+                                             key:
+                                                 *const ::std::os::raw::c_char,
+                                             klen: usize,
+                                             val:
+                                                 *const ::std::os::raw::c_char,
+                                             vlen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_merge_cf(b:
+                                                    *mut rocks_writebatch_with_index_t,
+                                                column_family:
+                                                    *mut rocks_column_family_handle_t,
+                                                key:
+                                                    *const ::std::os::raw::c_char,
+                                                klen: usize,
+                                                val:
+                                                    *const ::std::os::raw::c_char,
+                                                vlen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_delete(b:
+                                                  *mut rocks_writebatch_with_index_t,
+                                              key:
+                                                  *const ::std::os::raw::c_char,
+                                              klen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_delete_cf(b:
+                                                     *mut rocks_writebatch_with_index_t,
+                                                 column_family:
+                                                     *mut rocks_column_family_handle_t,
+                                                 key:
+                                                     *const ::std::os::raw::c_char,
+                                                 klen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_single_delete(b:
+                                                         *mut rocks_writebatch_with_index_t,
+                                                     key:
+                                                         *const ::std::os::raw::c_char,
+                                                     klen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_single_delete_cf(b:
+                                                            *mut rocks_writebatch_with_index_t,
+                                                        column_family:
+                                                            *mut rocks_column_family_handle_t,
+                                                        key:
+                                                            *const ::std::os::raw::c_char,
+                                                        klen: usize);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_get_writebatch(b:
+                                                          *mut rocks_writebatch_with_index_t)
+     -> *mut rocks_writebatch_t;
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_get_from_batch(b:
+                                                          *mut rocks_writebatch_with_index_t,
+                                                      options:
+                                                          *const rocks_dboptions_t,
+                                                      key:
+                                                          *const ::std::os::raw::c_char,
+                                                      klen: usize,
+                                                      value:
+                                                          *mut ::std::os::raw::c_void,
+                                                      status:
+                                                          *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_get_from_batch_cf(b:
+                                                             *mut rocks_writebatch_with_index_t,
+                                                         column_family:
+                                                             *mut rocks_column_family_handle_t,
+                                                         options:
+                                                             *const rocks_dboptions_t,
+                                                         key:
+                                                             *const ::std::os::raw::c_char,
+                                                         klen: usize,
+                                                         value:
+                                                             *mut ::std::os::raw::c_void,
+                                                         status:
+                                                             *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_get_from_batch_and_db(b:
+                                                                 *mut rocks_writebatch_with_index_t,
+                                                             db:
+                                                                 *mut rocks_db_t,
+                                                             options:
+                                                                 *const rocks_readoptions_t,
+                                                             key:
+                                                                 *const ::std::os::raw::c_char,
+                                                             klen: usize,
+                                                             value:
+                                                                 *mut rocks_pinnable_slice_t,
+                                                             status:
+                                                                 *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_writebatch_with_index_get_from_batch_and_db_cf(b:
+                                                                    *mut rocks_writebatch_with_index_t,
+                                                                db:
+                                                                    *mut rocks_db_t,
+                                                                options:
+                                                                    *const rocks_readoptions_t,
+                                                                column_family:
+                                                                    *mut rocks_column_family_handle_t,
+                                                                key:
+                                                                    *const ::std::os::raw::c_char,
+                                                                klen: usize,
+                                                                value:
+                                                                    *mut rocks_pinnable_slice_t,
+                                                                status:
+                                                                    *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_destroy(txn: *mut rocks_transaction_t);
+}
+extern "C" {
+    pub fn rocks_transaction_set_lock_timeout(txn: *mut rocks_transaction_t,
+                                              timeout: i64);
+}
+extern "C" {
+    pub fn rocks_transaction_commit(txn: *mut rocks_transaction_t,
+                                    status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_rollback(txn: *mut rocks_transaction_t,
+                                      status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_get(txn: *mut rocks_transaction_t,
+                                 options: *const rocks_readoptions_t,
+                                 key: *const ::std::os::raw::c_char,
+                                 klen: usize,
+                                 value: *mut ::std::os::raw::c_void,
+                                 status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_get_for_update(txn: *mut rocks_transaction_t,
+                                            options:
+                                                *const rocks_readoptions_t,
+                                            key:
+                                                *const ::std::os::raw::c_char,
+                                            klen: usize,
+                                            value:
+                                                *mut ::std::os::raw::c_void,
+                                            status:
+                                                *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_put(txn: *mut rocks_transaction_t,
+                                 key: *const ::std::os::raw::c_char,
+                                 klen: usize,
+                                 val: *const ::std::os::raw::c_char,
+                                 vlen: usize,
+                                 status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_merge(txn: *mut rocks_transaction_t,
+                                   key: *const ::std::os::raw::c_char,
+                                   klen: usize,
+                                   val: *const ::std::os::raw::c_char,
+                                   vlen: usize,
+                                   status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_transaction_delete(txn: *mut rocks_transaction_t,
+                                    key: *const ::std::os::raw::c_char,
+                                    klen: usize,
+                                    status: *mut *mut rocks_status_t);
+}
+extern "C" {
+    pub fn rocks_optimistic_transaction_db_open(options:
+                                                    *const rocks_options_t,
+                                                name:
+                                                    *const ::std::os::raw::c_char,
+                                                name_len: usize,
+                                                status:
+                                                    *mut *mut rocks_status_t)
+     -> *mut rocks_optimistic_transaction_db_t;
+}
+extern "C" {
+    pub fn rocks_optimistic_transaction_db_close(txn_db:
+                                                     *mut rocks_optimistic_transaction_db_t);
+}
+extern "C" {
+    pub fn rocks_optimistic_transaction_db_get_base_db(txn_db:
+                                                           *mut rocks_optimistic_transaction_db_t)
+     -> *mut rocks_db_t;
+}
+extern "C" {
+    pub fn rocks_optimistic_transaction_db_close_base_db(base_db:
+                                                             *mut rocks_db_t);
+}
+extern "C" {
+    pub fn rocks_optimistic_transaction_db_begin_transaction(txn_db:
+                                                                 *mut rocks_optimistic_transaction_db_t,
+                                                             write_options:
+                                                                 *const rocks_writeoptions_t)
+     -> *mut rocks_transaction_t;
+}
+extern "C" {
+    pub fn rocks_transactiondb_options_create()
+     -> *mut rocks_transactiondb_options_t;
+}
+extern "C" {
+    pub fn rocks_transactiondb_options_destroy(options:
+                                                   *mut rocks_transactiondb_options_t);
+}
+extern "C" {
+    pub fn rocks_transactiondb_options_set_max_num_locks(options:
+                                                             *mut rocks_transactiondb_options_t,
+                                                         v: i64);
+}
+extern "C" {
+    pub fn rocks_transactiondb_options_set_num_stripes(options:
+                                                           *mut rocks_transactiondb_options_t,
+                                                       v: usize);
+}
+extern "C" {
+    pub fn rocks_transactiondb_options_set_transaction_lock_timeout(options:
+                                                                        *mut rocks_transactiondb_options_t,
+                                                                    v: i64);
+}
+extern "C" {
+    pub fn rocks_transactiondb_options_set_default_lock_timeout(options:
+                                                                    *mut rocks_transactiondb_options_t,
+                                                                v: i64);
+}
+extern "C" {
+    pub fn rocks_transactiondb_open(options: *const rocks_options_t,
+                                    txn_db_options:
+                                        *const rocks_transactiondb_options_t,
+                                    name: *const ::std::os::raw::c_char,
+                                    name_len: usize,
+                                    status: *mut *mut rocks_status_t)
+     -> *mut rocks_transactiondb_t;
+}
+extern "C" {
+    pub fn rocks_transactiondb_close(txn_db: *mut rocks_transactiondb_t);
+}
+extern "C" {
+    pub fn rocks_transactiondb_get_base_db(txn_db: *mut rocks_transactiondb_t)
+     -> *mut rocks_db_t;
+}
+extern "C" {
+    pub fn rocks_transactiondb_close_base_db(base_db: *mut rocks_db_t);
+}
+extern "C" {
+    pub fn rocks_transactiondb_begin_transaction(txn_db:
+                                                     *mut rocks_transactiondb_t,
+                                                 write_options:
+                                                     *const rocks_writeoptions_t)
+     -> *mut rocks_transaction_t;
+}
 extern "C" {
     pub fn free(p: *mut ::std::os::raw::c_void);
 }
@@ -3860,3 +4591,8 @@ extern "C" {
     pub fn cxx_string_vector_nth_size(v: *mut cxx_string_vector_t,
                                       index: usize) -> usize;
 }
+extern "C" {
+    pub fn cxx_string_vector_push_back(v: *mut cxx_string_vector_t,
+                                       p: *const ::std::os::raw::c_char,
+                                       len: usize);
+}
@@ -0,0 +1,273 @@
+//! Incremental backups of a DB, via `BackupEngine`.
+
+use std::path::Path;
+use std::ptr;
+
+use rocks_sys as ll;
+
+use db::DBRef;
+use env::Env;
+use error::Status;
+use to_raw::ToRaw;
+use Result;
+
+/// Options for `BackupEngine`
+pub struct BackupEngineOptions {
+    raw: *mut ll::rocks_backup_engine_options_t,
+}
+
+impl ToRaw<ll::rocks_backup_engine_options_t> for BackupEngineOptions {
+    fn raw(&self) -> *mut ll::rocks_backup_engine_options_t {
+        self.raw
+    }
+}
+
+impl Drop for BackupEngineOptions {
+    fn drop(&mut self) {
+        unsafe {
+            ll::rocks_backup_engine_options_destroy(self.raw);
+        }
+    }
+}
+
+impl BackupEngineOptions {
+    /// Where to keep the backup files. Has to be different than dbname_
+    /// Best to set this to dbname_ + "/backups"
+    pub fn new<P: AsRef<Path>>(backup_dir: P) -> BackupEngineOptions {
+        let backup_dir = backup_dir.as_ref().to_str().expect("valid path");
+        BackupEngineOptions {
+            raw: unsafe {
+                ll::rocks_backup_engine_options_create(backup_dir.as_bytes().as_ptr() as *const _, backup_dir.len())
+            },
+        }
+    }
+
+    /// If share_table_files == true, backup will assume that table files with
+    /// same name have the same contents. This enables incremental backups and
+    /// avoids unnecessary data copies.
+    /// If share_table_files == false, each backup will be on its own and will
+    /// not share any data with other backups.
+    ///
+    /// Default: true
+    pub fn share_table_files(self, val: bool) -> Self {
+        unsafe {
+            ll::rocks_backup_engine_options_set_share_table_files(self.raw, val as u8);
+        }
+        self
+    }
+
+    /// If sync == true, we can guarantee you'll get consistent backup even
+    /// on a machine crash/reboot. Backup process is slower with sync enabled.
+    /// If sync == false, we don't guarantee anything on machine reboot. However,
+    /// chances are some of the backups are consistent.
+    ///
+    /// Default: true
+    pub fn sync(self, val: bool) -> Self {
+        unsafe {
+            ll::rocks_backup_engine_options_set_sync(self.raw, val as u8);
+        }
+        self
+    }
+
+    /// If true, it will delete whatever backups there are already
+    ///
+    /// Default: false
+    pub fn destroy_old_data(self, val: bool) -> Self {
+        unsafe {
+            ll::rocks_backup_engine_options_set_destroy_old_data(self.raw, val as u8);
+        }
+        self
+    }
+
+    /// If false, we won't backup log files. This option can be useful for backing
+    /// up in-memory databases where log file are persisted, but table files are in
+    /// memory.
+    ///
+    /// Default: true
+    pub fn backup_log_files(self, val: bool) -> Self {
+        unsafe {
+            ll::rocks_backup_engine_options_set_backup_log_files(self.raw, val as u8);
+        }
+        self
+    }
+
+    /// Up to this many background threads will copy files for CreateNewBackup()
+    /// and RestoreDBFromBackup()
+    ///
+    /// Default: 1
+    pub fn max_background_operations(self, val: i32) -> Self {
+        unsafe {
+            ll::rocks_backup_engine_options_set_max_background_operations(self.raw, val);
+        }
+        self
+    }
+}
+
+/// Information about a single backup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupInfo {
+    pub backup_id: u32,
+    /// Seconds since epoch, when the backup was created
+    pub timestamp: i64,
+    pub size: u64,
+    pub number_files: u32,
+}
+
+/// A backup engine for creating new backups and restoring from them.
+pub struct BackupEngine {
+    raw: *mut ll::rocks_backup_engine_t,
+}
+
+impl Drop for BackupEngine {
+    fn drop(&mut self) {
+        unsafe {
+            ll::rocks_backup_engine_close(self.raw);
+        }
+    }
+}
+
+impl BackupEngine {
+    /// Opens the backup engine, backups are read from and written to `options`' backup dir
+    /// via `env`.
+    pub fn open(options: &BackupEngineOptions, env: &'static Env) -> Result<BackupEngine> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_backup_engine_open(options.raw(), env.raw(), &mut status);
+            Status::from_ll(status).map(|_| BackupEngine { raw: raw })
+        }
+    }
+
+    /// Captures the state of the database in the latest backup.
+    ///
+    /// NOT a thread safe call
+    pub fn create_new_backup(&self, db: &DBRef) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_backup_engine_create_new_backup(self.raw, db.raw(), false as u8, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// Deletes old backups, keeping latest `num_backups_to_keep` alive
+    pub fn purge_old_backups(&self, num_backups_to_keep: u32) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_backup_engine_purge_old_backups(self.raw, num_backups_to_keep, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// Deletes a specific backup
+    pub fn delete_backup(&self, backup_id: u32) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_backup_engine_delete_backup(self.raw, backup_id, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// Checks that each file exists and that the size of the file matches our
+    /// expectations. it does not check file checksum.
+    pub fn verify_backup(&self, backup_id: u32) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_backup_engine_verify_backup(self.raw, backup_id, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// Returns info about backups in backup_info
+    pub fn get_backup_info(&self) -> Vec<BackupInfo> {
+        unsafe {
+            let cinfos = ll::rocks_backup_engine_get_backup_info(self.raw);
+            let num_infos = ll::rocks_backup_infos_size(cinfos);
+            let mut infos = Vec::with_capacity(num_infos);
+            for i in 0..num_infos {
+                infos.push(BackupInfo {
+                    backup_id: ll::rocks_backup_infos_nth_backup_id(cinfos, i),
+                    timestamp: ll::rocks_backup_infos_nth_timestamp(cinfos, i),
+                    size: ll::rocks_backup_infos_nth_size(cinfos, i),
+                    number_files: ll::rocks_backup_infos_nth_number_files(cinfos, i),
+                })
+            }
+            ll::rocks_backup_infos_destroy(cinfos);
+            infos
+        }
+    }
+
+    /// Restores the latest backup into `db_dir`, with WAL files in `wal_dir`.
+    ///
+    /// The DB must not be open while restoring.
+    pub fn restore_from_latest_backup<P: AsRef<Path>, Q: AsRef<Path>>(&self, db_dir: P, wal_dir: Q) -> Result<()> {
+        let db_dir = db_dir.as_ref().to_str().expect("valid path");
+        let wal_dir = wal_dir.as_ref().to_str().expect("valid path");
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_backup_engine_restore_db_from_latest_backup(
+                self.raw,
+                db_dir.as_bytes().as_ptr() as *const _,
+                db_dir.len(),
+                wal_dir.as_bytes().as_ptr() as *const _,
+                wal_dir.len(),
+                false as u8,
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn backup_and_restore() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let backup_dir = ::tempdir::TempDir::new_in(".", "backup").unwrap();
+        let db_path = tmp_dir.path().join("db");
+
+        let engine = BackupEngine::open(&BackupEngineOptions::new(&backup_dir), Env::default_instance()).unwrap();
+        assert!(engine.get_backup_info().is_empty());
+
+        {
+            let db = DB::open(
+                Options::default().map_db_options(|db| db.create_if_missing(true)),
+                &db_path,
+            ).unwrap();
+            for i in 0..100 {
+                let key = format!("k{}", i);
+                let val = format!("v{}", i * i);
+                assert!(db.put(&Default::default(), key.as_bytes(), val.as_bytes()).is_ok());
+            }
+            assert!(engine.create_new_backup(&db).is_ok());
+
+            assert!(db.put(&Default::default(), b"k100", b"v10000").is_ok());
+            assert!(engine.create_new_backup(&db).is_ok());
+        }
+
+        let infos = engine.get_backup_info();
+        assert_eq!(infos.len(), 2);
+        assert!(infos[0].backup_id < infos[1].backup_id);
+        assert!(infos[1].size > 0);
+        assert!(infos[1].timestamp > 0);
+        assert!(engine.verify_backup(infos[1].backup_id).is_ok());
+
+        assert!(engine.purge_old_backups(1).is_ok());
+        assert_eq!(engine.get_backup_info().len(), 1);
+
+        fs::remove_dir_all(&db_path).unwrap();
+        assert!(engine.restore_from_latest_backup(&db_path, &db_path).is_ok());
+
+        let db = DB::open(&Options::default(), &db_path).unwrap();
+        for i in 0..101 {
+            let key = format!("k{}", i);
+            let val = format!("v{}", i * i);
+            assert_eq!(db.get(&Default::default(), key.as_bytes()).unwrap(), val.as_bytes());
+        }
+    }
+}
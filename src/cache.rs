@@ -9,10 +9,13 @@
 
 use std::os::raw::c_char;
 use std::ffi::CStr;
+use std::ptr;
 
 use rocks_sys as ll;
 
+use error::Status;
 use to_raw::ToRaw;
+use Result;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum Priority {
@@ -20,8 +23,6 @@ pub enum Priority {
     Low,
 }
 
-// TODO: impl Copy for inner shared_ptr
-
 /// A builtin cache implementation with a least-recently-used eviction
 /// policy is provided.  Clients may use their own implementations if
 /// they want something more sophisticated (like scan-resistance, a
@@ -36,6 +37,13 @@ impl ToRaw<ll::rocks_cache_t> for Cache {
     }
 }
 
+impl Clone for Cache {
+    /// Duplicated Cache inner shared_ptr
+    fn clone(&self) -> Self {
+        Cache { raw: unsafe { ll::rocks_cache_clone(self.raw) } }
+    }
+}
+
 impl Cache {
     /// The type of the Cache
     pub fn name(&self) -> &str {
@@ -64,6 +72,38 @@ impl Cache {
     pub fn get_usage(&self) -> usize {
         unsafe { ll::rocks_cache_get_usage(self.raw) }
     }
+
+    /// Insert a mapping from key->value into the cache and assign it
+    /// the specified charge against the total cache capacity.
+    /// If strict_capacity_limit is true and cache reaches its full capacity,
+    /// return `Status::Incomplete`.
+    ///
+    /// Rust: value is copied into the cache, the handle is released right away. Since
+    /// no handle is held, a full cache evicts entries or drops the new one instead of returning `Incomplete`.
+    pub fn insert(&self, key: &[u8], value: &[u8], charge: usize) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_cache_insert(
+                self.raw,
+                key.as_ptr() as *const _,
+                key.len(),
+                value.as_ptr() as *const _,
+                value.len(),
+                charge,
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+
+    /// If the cache contains entry for key, erase it.  Note that the
+    /// underlying entry will be kept around until all existing handles
+    /// to it have been released.
+    pub fn erase(&self, key: &[u8]) {
+        unsafe {
+            ll::rocks_cache_erase(self.raw, key.as_ptr() as *const _, key.len());
+        }
+    }
 }
 
 impl Drop for Cache {
@@ -184,6 +224,40 @@ mod tests {
         assert!(lru_cache.get_usage() == 0);
     }
 
+    #[test]
+    fn cache_insert_and_erase() {
+        let cache = CacheBuilder::new_lru(1024).num_shard_bits(0).build().unwrap();
+        assert!(cache.insert(b"key1", b"value1", 100).is_ok());
+        assert!(cache.insert(b"key2", b"value2", 200).is_ok());
+        let usage = cache.get_usage();
+        assert!(usage >= 300);
+
+        cache.erase(b"key1");
+        assert_eq!(cache.get_usage(), usage - 100);
+        // erasing a missing key is a no-op
+        cache.erase(b"key1");
+        cache.erase(b"not-exist");
+        assert_eq!(cache.get_usage(), usage - 100);
+
+        cache.erase(b"key2");
+        assert_eq!(cache.get_usage(), usage - 300);
+
+        let strict = CacheBuilder::new_lru(100)
+            .num_shard_bits(0)
+            .strict_capacity_limit(true)
+            .build()
+            .unwrap();
+        // entries are released right after insert, so they are evicted to make room
+        for key in &[b"k1", b"k2", b"k3"] {
+            assert!(strict.insert(*key, b"value", 50).is_ok());
+            assert!(strict.get_usage() <= strict.get_capacity());
+        }
+        assert!(strict.get_usage() >= 50);
+        // an entry larger than the capacity is never kept
+        assert!(strict.insert(b"huge", b"value", 150).is_ok());
+        assert!(strict.get_usage() <= strict.get_capacity());
+    }
+
     #[test]
     fn lru_cache_db() {
         let tmp_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
@@ -0,0 +1,114 @@
+//! A checkpoint is an openable snapshot of a database at a point in time.
+
+use std::path::Path;
+use std::ptr;
+use std::marker::PhantomData;
+
+use rocks_sys as ll;
+
+use db::DB;
+use error::Status;
+use to_raw::ToRaw;
+use Result;
+
+/// Creates openable snapshots of a DB, using hard links for SST files
+/// when the checkpoint directory is on the same filesystem.
+pub struct Checkpoint<'a> {
+    raw: *mut ll::rocks_checkpoint_t,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Drop for Checkpoint<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ll::rocks_checkpoint_destroy(self.raw);
+        }
+    }
+}
+
+impl<'a> Checkpoint<'a> {
+    /// Creates a Checkpoint object to be used for creating openable snapshots
+    pub fn new(db: &'a DB) -> Result<Checkpoint<'a>> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_checkpoint_create(db.raw(), &mut status);
+            Status::from_ll(status).map(|_| {
+                Checkpoint {
+                    raw: raw,
+                    _marker: PhantomData,
+                }
+            })
+        }
+    }
+
+    /// Builds an openable snapshot of RocksDB on the same disk, which
+    /// accepts an output directory on the same disk, and under the directory
+    /// (1) hard-linked SST files pointing to existing live SST files
+    /// SST files will be copied if output directory is on a different filesystem
+    /// (2) a copied manifest files and other files
+    ///
+    /// The directory should not already exist and will be created by this API.
+    /// The directory will be an absolute path
+    ///
+    /// `log_size_for_flush`: if the total log file size is equal or larger than
+    /// this value, then a flush is triggered for all the column families. The
+    /// default value is 0, which means flush is always triggered. If you move
+    /// away from the default, the checkpoint may not contain up-to-date data
+    /// if WAL writing is not always enabled.
+    /// Flush will always trigger if it is 2PC.
+    pub fn create_checkpoint<P: AsRef<Path>>(&self, checkpoint_dir: P, log_size_for_flush: u64) -> Result<()> {
+        let dir = checkpoint_dir.as_ref().to_str().expect("valid path");
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_checkpoint_create_checkpoint(
+                self.raw,
+                dir.as_bytes().as_ptr() as *const _,
+                dir.len(),
+                log_size_for_flush,
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn checkpoint() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let checkpoint_dir = ::tempdir::TempDir::new_in(".", "checkpoint").unwrap();
+        let checkpoint_path = checkpoint_dir.path().join("snapshot");
+
+        let db = DB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..100 {
+            let key = format!("k{}", i);
+            let val = format!("v{}", i * i);
+            assert!(db.put(&Default::default(), key.as_bytes(), val.as_bytes()).is_ok());
+        }
+
+        let checkpoint = Checkpoint::new(&db).unwrap();
+        assert!(checkpoint.create_checkpoint(&checkpoint_path, 0).is_ok());
+        // target directory must not exist
+        assert!(checkpoint.create_checkpoint(&checkpoint_path, 0).is_err());
+
+        // later writes are not part of the checkpoint
+        assert!(db.put(&Default::default(), b"k100", b"v10000").is_ok());
+
+        let snapshot_db = DB::open(&Options::default(), &checkpoint_path).unwrap();
+        for i in 0..100 {
+            let key = format!("k{}", i);
+            let val = format!("v{}", i * i);
+            assert_eq!(snapshot_db.get(&Default::default(), key.as_bytes()).unwrap(), val.as_bytes());
+        }
+        assert!(snapshot_db.get(&Default::default(), b"k100").unwrap_err().is_not_found());
+    }
+}
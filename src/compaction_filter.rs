@@ -175,6 +175,8 @@ pub trait CompactionFilterFactory {
 }
 
 /// Context information of a compaction run
+///
+/// Same layout as `CompactionFilter::Context`.
 #[repr(C)]
 pub struct Context {
     /// Does this compaction run include all data files
@@ -238,6 +240,32 @@ pub mod c {
         let filter = f as *mut Box<CompactionFilter>;
         (*filter).ignore_snapshots() as c_uchar
     }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_compaction_filter_factory_create_compaction_filter(
+        f: *mut (),
+        context: &Context,
+    ) -> *mut () {
+        assert!(!f.is_null());
+        let factory = f as *mut Box<CompactionFilterFactory>;
+        let filter = (*factory).create_compaction_filter(context);
+        // dropped by rocks_compaction_filter_t
+        Box::into_raw(Box::new(filter)) as *mut () // Box<Box<CompactionFilter>>
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_compaction_filter_factory_drop(f: *mut ()) {
+        assert!(!f.is_null());
+        let factory = f as *mut Box<CompactionFilterFactory>;
+        Box::from_raw(factory);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_compaction_filter_factory_name(f: *mut ()) -> *const c_char {
+        assert!(!f.is_null());
+        let factory = f as *mut Box<CompactionFilterFactory>;
+        (*factory).name().as_ptr() as *const _
+    }
 }
 
 
@@ -331,4 +359,124 @@ mod tests {
         drop(db);
         drop(tmp_dir);
     }
+
+    // value layout: 8 bytes big-endian expiration timestamp, then payload
+    fn ttl_value(expire_at: u64, payload: &[u8]) -> Vec<u8> {
+        let mut value = (0..8).rev().map(|i| (expire_at >> (i * 8)) as u8).collect::<Vec<u8>>();
+        value.extend_from_slice(payload);
+        value
+    }
+
+    fn ttl_expire_at(value: &[u8]) -> u64 {
+        value[..8].iter().fold(0, |acc, &b| (acc << 8) | b as u64)
+    }
+
+    pub struct TtlCompactionFilter {
+        now: u64,
+    }
+
+    impl CompactionFilter for TtlCompactionFilter {
+        fn filter(&self, level: u32, key: &[u8], value_type: ValueType, existing_value: &[u8]) -> Decision {
+            if existing_value.len() >= 8 && ttl_expire_at(existing_value) <= self.now {
+                Decision::Remove
+            } else {
+                Decision::Keep
+            }
+        }
+    }
+
+    #[test]
+    fn compaction_filter_ttl_in_value() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let now = 1_500_000_000;
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.compaction_filter(Box::new(TtlCompactionFilter { now: now }))),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..100 {
+            let key = format!("key-{:03}", i);
+            // every other key is already expired
+            let expire_at = if i % 2 == 0 { now - 60 } else { now + 3600 };
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), &ttl_value(expire_at, b"payload")).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        // nothing is dropped until a compaction runs
+        assert!(db.get(&ReadOptions::default(), b"key-000").is_ok());
+
+        let ret = db.compact_range(&Default::default(), ..);
+        assert!(ret.is_ok(), "error: {:?}", ret);
+
+        for i in 0..100 {
+            let key = format!("key-{:03}", i);
+            let ret = db.get(&ReadOptions::default(), key.as_bytes());
+            if i % 2 == 0 {
+                assert!(ret.unwrap_err().is_not_found());
+            } else {
+                let value = ret.unwrap();
+                assert_eq!(ttl_expire_at(&value), now + 3600);
+                assert_eq!(&value[8..], b"payload");
+            }
+        }
+        assert_eq!(db.iter(&ReadOptions::default()).count(), 50);
+    }
+
+    #[test]
+    fn compaction_filter_factory_ttl() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // snapshots the clock once per compaction run
+        struct TtlFilterFactory {
+            clock: Arc<AtomicUsize>,
+            manual_compactions: Arc<AtomicUsize>,
+        }
+
+        impl CompactionFilterFactory for TtlFilterFactory {
+            fn create_compaction_filter(&self, context: &Context) -> Box<CompactionFilter> {
+                if context.is_manual_compaction {
+                    self.manual_compactions.fetch_add(1, Ordering::SeqCst);
+                }
+                Box::new(TtlCompactionFilter { now: self.clock.load(Ordering::SeqCst) as u64 })
+            }
+        }
+
+        let clock = Arc::new(AtomicUsize::new(1000));
+        let manual_compactions = Arc::new(AtomicUsize::new(0));
+        let factory = TtlFilterFactory {
+            clock: clock.clone(),
+            manual_compactions: manual_compactions.clone(),
+        };
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.compaction_filter_factory(Box::new(factory))),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..20 {
+            let key = format!("key-{:02}", i);
+            let expire_at = if i < 10 { 1010 } else { 1100 };
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), &ttl_value(expire_at, b"payload")).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        // nothing has expired yet
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert_eq!(db.iter(&ReadOptions::default()).count(), 20);
+
+        // a later compaction picks up the new clock
+        clock.store(1050, Ordering::SeqCst);
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert_eq!(db.iter(&ReadOptions::default()).count(), 10);
+        assert!(db.get(&ReadOptions::default(), b"key-00").unwrap_err().is_not_found());
+        assert!(db.get(&ReadOptions::default(), b"key-10").is_ok());
+
+        assert!(manual_compactions.load(Ordering::SeqCst) >= 2);
+    }
 }
@@ -53,7 +53,9 @@ pub trait Comparator {
     /// If `*start < limit`, changes `*start` to a short string in `[start,limit)`.
     /// Simple comparator implementations may return with `*start` unchanged,
     /// i.e., an implementation of this method that does nothing is correct.
-    fn find_shortest_separator(&self, start: &[u8], limit: &[u8]) -> Option<&[u8]> {
+    ///
+    /// Rust: return `None` to keep `start` unchanged, `Some(new_start)` to replace it.
+    fn find_shortest_separator(&self, start: &[u8], limit: &[u8]) -> Option<Vec<u8>> {
         None
     }
 
@@ -61,7 +63,9 @@ pub trait Comparator {
     ///
     /// Simple comparator implementations may return with `*key` unchanged,
     /// i.e., an implementation of this method that does nothing is correct.
-    fn find_short_successor(&self, key: &[u8]) -> Option<&[u8]> {
+    ///
+    /// Rust: return `None` to keep `key` unchanged, `Some(new_key)` to replace it.
+    fn find_short_successor(&self, key: &[u8]) -> Option<Vec<u8>> {
         None
     }
 }
@@ -138,6 +142,7 @@ pub mod c {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
     use super::*;
     use super::super::rocksdb::*;
 
@@ -232,4 +237,123 @@ mod tests {
         // println!("keys => {:?}", ks);
         assert_eq!(ks, vec!["Key1", "kEy2", "kEY3", "key4"]);
     }
+
+    #[test]
+    fn bitwise_comparator_reversed_sst_file() {
+        use sst_file_writer::SstFileWriter;
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let sst_dir = ::tempdir::TempDir::new_in(".", "sst").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.bitwise_comparator_reversed(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        // big-endian timestamps, newest first
+        for ts in 0u64..10 {
+            let key = [0, 0, 0, 0, 0, 0, 0, ts as u8];
+            assert!(db.put(&WriteOptions::default(), &key, b"mem").is_ok());
+        }
+
+        let writer = SstFileWriter::builder().bitwise_comparator_reversed(true).build();
+        writer.open(sst_dir.path().join("2333.sst")).unwrap();
+        // in comparator order
+        for ts in (10u64..20).rev() {
+            let key = [0, 0, 0, 0, 0, 0, 0, ts as u8];
+            assert!(writer.put(&key, b"sst").is_ok());
+        }
+        assert!(writer.put(&[0, 0, 0, 0, 0, 0, 0, 20], b"sst").is_err());
+        assert!(writer.finish().is_ok());
+
+        let ret = db.ingest_external_file(&[sst_dir.path().join("2333.sst")], &IngestExternalFileOptions::default());
+        assert!(ret.is_ok(), "ingest: {:?}", ret);
+
+        let mut it = db.new_iterator(&ReadOptions::default());
+        it.seek_to_first();
+        let mut keys = vec![];
+        while it.is_valid() {
+            keys.push(it.key()[7] as u64);
+            it.next();
+        }
+        assert_eq!(keys, (0u64..20).rev().collect::<Vec<_>>());
+    }
+
+    pub struct ShortSeparatorComparator {
+        separator_calls: AtomicUsize,
+    }
+
+    impl Comparator for ShortSeparatorComparator {
+        fn compare(&self, a: &[u8], b: &[u8]) -> Ordering {
+            a.cmp(b)
+        }
+
+        fn name(&self) -> &str {
+            "rust-rocks.ShortSeparatorComparator\0"
+        }
+
+        fn find_shortest_separator(&self, start: &[u8], limit: &[u8]) -> Option<Vec<u8>> {
+            self.separator_calls.fetch_add(1, AtomicOrdering::SeqCst);
+            let diff_index = start.iter().zip(limit).take_while(|&(a, b)| a == b).count();
+            if diff_index >= start.len() || diff_index >= limit.len() {
+                return None;
+            }
+            let diff_byte = start[diff_index];
+            if diff_byte < 0xff && diff_byte + 1 < limit[diff_index] {
+                let mut new_start = start[..diff_index + 1].to_vec();
+                new_start[diff_index] += 1;
+                Some(new_start)
+            } else {
+                None
+            }
+        }
+
+        fn find_short_successor(&self, key: &[u8]) -> Option<Vec<u8>> {
+            key.iter().position(|&b| b != 0xff).map(|i| {
+                let mut new_key = key[..i + 1].to_vec();
+                new_key[i] += 1;
+                new_key
+            })
+        }
+    }
+
+    lazy_static! {
+        static ref SHORT_SEPARATOR_CMP: ShortSeparatorComparator = {
+            ShortSeparatorComparator { separator_calls: AtomicUsize::new(0) }
+        };
+    }
+
+    #[test]
+    fn custom_comparator_shortest_separator() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+
+        let opts = Options::default()
+            .map_db_options(|db| db.create_if_missing(true))
+            .map_cf_options(|cf| cf.comparator(&*SHORT_SEPARATOR_CMP));
+        let db = DB::open(opts, &tmp_dir).unwrap();
+
+        let value = vec![b'v'; 200];
+        for i in 0..2000 {
+            let key = format!("key-{:05}-{}", i * 10, "x".repeat(i % 30));
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), &value).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        // index blocks are built with the shortened keys
+        assert!(SHORT_SEPARATOR_CMP.separator_calls.load(AtomicOrdering::SeqCst) > 0);
+
+        for i in 0..2000 {
+            let key = format!("key-{:05}-{}", i * 10, "x".repeat(i % 30));
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), &value[..]);
+            // keys between the written ones are not found
+            let key = format!("key-{:05}", i * 10 + 5);
+            assert!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap_err().is_not_found());
+        }
+
+        let mut it = db.new_iterator(&ReadOptions::default());
+        it.seek(b"key-00105");
+        assert!(it.is_valid());
+        assert_eq!(it.key(), format!("key-{:05}-{}", 11 * 10, "x".repeat(11 % 30)).as_bytes());
+    }
 }
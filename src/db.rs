@@ -13,6 +13,7 @@ use std::marker::PhantomData;
 use std::path::Path;
 use std::collections::hash_map::HashMap;
 use std::borrow::Borrow;
+use std::sync::Mutex;
 
 use rocks_sys as ll;
 
@@ -22,11 +23,11 @@ use options::{ColumnFamilyOptions, CompactRangeOptions, CompactionOptions, DBOpt
 use table_properties::TablePropertiesCollection;
 use snapshot::Snapshot;
 use write_batch::WriteBatch;
-use iterator::Iterator;
+use iterator::{DbIterator, Iterator};
 use types::SequenceNumber;
 use to_raw::{FromRaw, ToRaw};
 use metadata::{ColumnFamilyMetaData, LevelMetaData, LiveFileMetaData, SstFileMetaData};
-use transaction_log::{LogFile, TransactionLogIterator};
+use transaction_log::{BatchResult, LogFile, TransactionLogIterator, WalTail};
 use debug::KeyVersionVec;
 
 use super::Result;
@@ -106,6 +107,15 @@ impl AsRef<ColumnFamilyHandle> for ColumnFamilyHandle {
     }
 }
 
+impl PartialEq for ColumnFamilyHandle {
+    /// Handles are equal when they refer to the same column family ID
+    fn eq(&self, other: &ColumnFamilyHandle) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for ColumnFamilyHandle {}
+
 impl fmt::Debug for ColumnFamilyHandle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "CFHandle(id={}, name={:?})", self.id(), self.name())
@@ -139,25 +149,12 @@ impl ColumnFamilyHandle {
     }
 }
 
-/// An opened column family, owned for RAII style management
+/// An opened column family, borrowing the DB.
+///
+/// The underlying column family handle is owned by the DB, and is destroyed when the DB is closed.
 pub struct ColumnFamily<'a, 'b: 'a> {
     handle: ColumnFamilyHandle,
     db: &'a DBRef<'b>,
-    owned: bool,
-}
-
-impl<'a, 'b> Drop for ColumnFamily<'a, 'b> {
-    fn drop(&mut self) {
-        if self.owned {
-            let mut status = ptr::null_mut::<ll::rocks_status_t>();
-            unsafe {
-                ll::rocks_db_destroy_column_family_handle(self.db.raw, self.raw(), &mut status);
-                assert!(Status::from_ll(status).is_ok());
-                // make underlying cf_handle a nullptr, rocks-sys will skip delete it.
-                self.handle.raw = ptr::null_mut();
-            }
-        }
-    }
 }
 
 impl<'a, 'b> AsRef<ColumnFamilyHandle> for ColumnFamily<'a, 'b> {
@@ -625,6 +622,15 @@ impl<'a> ToRaw<ll::rocks_db_t> for DBRef<'a> {
     }
 }
 
+impl<'a> FromRaw<ll::rocks_db_t> for DBRef<'a> {
+    unsafe fn from_ll(raw: *mut ll::rocks_db_t) -> DBRef<'a> {
+        DBRef {
+            raw: raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
 unsafe impl<'a> Sync for DBRef<'a> {}
 unsafe impl<'a> Send for DBRef<'a> {}
 
@@ -651,12 +657,22 @@ unsafe impl<'a> Send for DBRef<'a> {}
 /// ```
 pub struct DB<'a> {
     context: Box<DBRef<'a>>,
+    // column family handles opened or created via this DB, with a dropped flag,
+    // destroyed on close
+    cf_handles: Mutex<Vec<(Box<ColumnFamilyHandle>, bool)>>,
 }
 
 impl<'a> Drop for DB<'a> {
     #[inline]
     fn drop(&mut self) {
         unsafe {
+            for (mut handle, _) in self.cf_handles.lock().unwrap().drain(..) {
+                let mut status = ptr::null_mut::<ll::rocks_status_t>();
+                ll::rocks_db_destroy_column_family_handle(self.context.raw(), handle.raw(), &mut status);
+                assert!(Status::from_ll(status).is_ok());
+                // make underlying cf_handle a nullptr, rocks-sys will skip delete it.
+                handle.raw = ptr::null_mut();
+            }
             ll::rocks_db_close(self.context.raw());
         }
     }
@@ -697,7 +713,10 @@ impl<'a> FromRaw<ll::rocks_db_t> for DB<'a> {
             raw: raw,
             _marker: PhantomData,
         };
-        DB { context: Box::new(context) }
+        DB {
+            context: Box::new(context),
+            cf_handles: Mutex::new(Vec::new()),
+        }
     }
 }
 
@@ -786,20 +805,12 @@ impl<'a> DB<'a> {
             Status::from_ll(status).map(|_| {
                 let db = DB::from_ll(db_ptr);
                 // lifetime transmute
-                let db_ref = mem::transmute(&*db.context);
-                (
-                    db,
-                    cfhandles
-                        .into_iter()
-                        .map(|p| {
-                            ColumnFamily {
-                                handle: ColumnFamilyHandle { raw: p },
-                                db: db_ref,
-                                owned: true,
-                            }
-                        })
-                        .collect(),
-                )
+                let db_ref: &DB = mem::transmute(&db);
+                let cfs = cfhandles
+                    .into_iter()
+                    .map(|p| db_ref.register_cf_handle(p))
+                    .collect();
+                (db, cfs)
             })
         }
     }
@@ -808,7 +819,10 @@ impl<'a> DB<'a> {
     /// that modify data, like `put/delete`, will return error.
     /// If the db is opened in read only mode, then no compactions
     /// will happen.
-    pub fn open_for_readonly<'b, P: AsRef<Path>>(
+    ///
+    /// No file lock is taken, so multiple processes can open the same
+    /// DB read only, even alongside a writer.
+    pub fn open_for_read_only<'b, P: AsRef<Path>>(
         options: &Options,
         name: P,
         error_if_log_file_exist: bool,
@@ -829,6 +843,72 @@ impl<'a> DB<'a> {
         }
     }
 
+    #[deprecated(note = "renamed to `open_for_read_only`")]
+    pub fn open_for_readonly<'b, P: AsRef<Path>>(
+        options: &Options,
+        name: P,
+        error_if_log_file_exist: bool,
+    ) -> Result<DB<'b>> {
+        DB::open_for_read_only(options, name, error_if_log_file_exist)
+    }
+
+
+    /// Create a column_family and return the handle of column family
+    /// through the argument handle.
+    pub fn create_column_family(&self, cfopts: &ColumnFamilyOptions, column_family_name: &str) -> Result<ColumnFamily> {
+        let dbname = CString::new(column_family_name).unwrap();
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let handle = ll::rocks_db_create_column_family(self.raw(), cfopts.raw(), dbname.as_ptr(), &mut status);
+            Status::from_ll(status).map(|_| self.register_cf_handle(handle))
+        }
+    }
+
+    /// Returns a column family handle opened or created via this DB, by name.
+    ///
+    /// Rust: handles live until the DB is closed, if the name has been created more
+    /// than once, the most recent handle is returned. Dropped column families are skipped.
+    pub fn cf_handle(&self, name: &str) -> Option<&ColumnFamilyHandle> {
+        let handles = self.cf_handles.lock().unwrap();
+        handles
+            .iter()
+            .rev()
+            .find(|&&(ref handle, dropped)| !dropped && handle.name() == name)
+            // boxed handles are never removed or freed before the DB is closed
+            .map(|&(ref handle, _)| unsafe { &*(&**handle as *const ColumnFamilyHandle) })
+    }
+
+    /// Drop a column family specified by column_family handle. This call
+    /// only records a drop record in the manifest and prevents the column
+    /// family from flushing and compacting.
+    ///
+    /// Rust: `cf_handle` no longer returns the handle owned by the DB, handles obtained
+    /// earlier stay valid until the DB is closed.
+    pub fn drop_column_family(&self, column_family: &ColumnFamilyHandle) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_db_drop_column_family(self.raw(), column_family.raw(), &mut status);
+            try!(Status::from_ll(status));
+        }
+        let mut handles = self.cf_handles.lock().unwrap();
+        if let Some(&mut (_, ref mut dropped)) = handles.iter_mut().find(|&&mut (ref handle, _)| **handle == *column_family) {
+            *dropped = true;
+        }
+        Ok(())
+    }
+
+    /// Takes ownership of a newly opened handle, returns a `ColumnFamily` borrowing it.
+    fn register_cf_handle(&self, raw: *mut ll::rocks_column_family_handle_t) -> ColumnFamily {
+        let handle = ColumnFamilyHandle { raw: unsafe { ll::rocks_column_family_handle_clone(raw) } };
+        self.cf_handles
+            .lock()
+            .unwrap()
+            .push((Box::new(ColumnFamilyHandle { raw: raw }), false));
+        ColumnFamily {
+            handle: handle,
+            db: self.borrow(),
+        }
+    }
 
     /// `ListColumnFamilies` will open the DB specified by argument name
     /// and return the list of all column nfamilies in that DB
@@ -863,34 +943,6 @@ impl<'a> DB<'a> {
 }
 
 impl<'a> DBRef<'a> {
-    /// Create a column_family and return the handle of column family
-    /// through the argument handle.
-    pub fn create_column_family(&self, cfopts: &ColumnFamilyOptions, column_family_name: &str) -> Result<ColumnFamily> {
-        let dbname = CString::new(column_family_name).unwrap();
-        let mut status = ptr::null_mut::<ll::rocks_status_t>();
-        unsafe {
-            let handle = ll::rocks_db_create_column_family(self.raw(), cfopts.raw(), dbname.as_ptr(), &mut status);
-            Status::from_ll(status).map(|_| {
-                ColumnFamily {
-                    handle: ColumnFamilyHandle { raw: handle },
-                    db: self.borrow(),
-                    owned: true,
-                }
-            })
-        }
-    }
-
-    /// Drop a column family specified by column_family handle. This call
-    /// only records a drop record in the manifest and prevents the column
-    /// family from flushing and compacting.
-    pub fn drop_column_family(&self, column_family: &ColumnFamilyHandle) -> Result<()> {
-        let mut status = ptr::null_mut::<ll::rocks_status_t>();
-        unsafe {
-            ll::rocks_db_drop_column_family(self.raw(), column_family.raw(), &mut status);
-            Status::from_ll(status)
-        }
-    }
-
     /// Set the database entry for `"key"` to `"value"`.
     /// If `"key"` already exists, it will be overwritten.
     /// Returns OK on success, and a non-OK status on error.
@@ -1105,6 +1157,15 @@ impl<'a> DBRef<'a> {
         }
     }
 
+    /// Like `write`, and returns the latest sequence number after applying
+    /// `updates`, the sequence number of its last update.
+    ///
+    /// Rust: read with `GetLatestSequenceNumber` after the write, concurrent
+    /// writes to this DB may already have advanced it.
+    pub fn write_and_get_seq(&self, options: &WriteOptions, updates: WriteBatch) -> Result<SequenceNumber> {
+        self.write(options, updates).map(|()| self.get_latest_sequence_number())
+    }
+
     /// If the database contains an entry for "key" store the
     /// corresponding value in *value and return OK.
     ///
@@ -1129,6 +1190,45 @@ impl<'a> DBRef<'a> {
         }
     }
 
+    /// Like `get`, but reuses `value` instead of allocating a new `PinnableSlice`.
+    ///
+    /// `value` is reset before reading. Returns `Ok(false)` and leaves `value`
+    /// empty if there is no entry for "key".
+    pub fn get_pinned_into(&self, options: &ReadOptions, key: &[u8], value: &mut PinnableSlice) -> Result<bool> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        value.reset();
+        unsafe {
+            ll::rocks_db_get_pinnable(
+                self.raw(),
+                options.raw(),
+                key.as_ptr() as *const _,
+                key.len(),
+                value.raw(),
+                &mut status,
+            );
+            match Status::from_ll(status) {
+                Ok(()) => Ok(true),
+                Err(ref e) if e.is_not_found() => Ok(false),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    /// Like `multi_get`, but values are read into `PinnableSlice`s, `Ok(None)`
+    /// for keys that are not found.
+    ///
+    /// Rust: RocksDB 5.8 has no `MultiGet` into `PinnableSlice`, it's a `Get` per
+    /// key, set `ReadOptions::snapshot` for a consistent view across keys.
+    pub fn multi_get_pinned(&self, options: &ReadOptions, keys: &[&[u8]]) -> Vec<Result<Option<PinnableSlice>>> {
+        keys.iter()
+            .map(|key| {
+                let mut value = PinnableSlice::new();
+                self.get_pinned_into(options, key, &mut value)
+                    .map(|found| if found { Some(value) } else { None })
+            })
+            .collect()
+    }
+
     pub fn get_cf(
         &self,
         options: &ReadOptions,
@@ -1340,6 +1440,15 @@ impl<'a> DBRef<'a> {
         }
     }
 
+    /// Returns a rust-style iterator over all key/value pairs of the default column family,
+    /// from the first key.
+    ///
+    /// Rust: `iterate_upper_bound` and other `ReadOptions` are respected, `options` must
+    /// outlive the iterator.
+    pub fn iter<'c, 'd: 'c>(&'d self, options: &'c ReadOptions) -> DbIterator<'c> {
+        DbIterator::new(self.new_iterator(options))
+    }
+
     pub fn new_iterator_cf<'c, 'd: 'c>(&self, options: &ReadOptions, cf: &'d ColumnFamilyHandle) -> Iterator<'c> {
         unsafe {
             let ptr = ll::rocks_db_create_iterator_cf(self.raw(), options.raw(), cf.raw());
@@ -1509,6 +1618,19 @@ impl<'a> DBRef<'a> {
         if ok { Some(val) } else { None }
     }
 
+    /// Reset internal stats for DB and all column families, as reported by
+    /// the `"rocksdb.stats"` family of properties.
+    ///
+    /// Note this doesn't reset `DBOptions::statistics` as it is not owned by
+    /// DB.
+    pub fn reset_stats(&self) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_db_reset_stats(self.raw(), &mut status);
+            Status::from_ll(status)
+        }
+    }
+
     /// For each i in [0,n-1], store in "sizes[i]", the approximate
     /// file system space used by keys in "[range[i].start .. range[i].limit)".
     ///
@@ -1624,6 +1746,30 @@ impl<'a> DBRef<'a> {
         }
     }
 
+    /// Compact the underlying storage for the key range of `column_family`,
+    /// other column families are left untouched. See `compact_range()`.
+    pub fn compact_range_cf<R: ToCompactRange>(
+        &self,
+        options: &CompactRangeOptions,
+        column_family: &ColumnFamilyHandle,
+        range: R,
+    ) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_db_compact_range_opt_cf(
+                self.raw(),
+                options.raw(),
+                column_family.raw(),
+                range.start_key() as *const _,
+                range.start_key_len(),
+                range.end_key() as *const _,
+                range.end_key_len(),
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+
     pub fn set_options(&self, new_options: &HashMap<&str, &str>) -> Result<()> {
         self.set_options_cf(&self.default_column_family(), new_options)
     }
@@ -1708,6 +1854,34 @@ impl<'a> DBRef<'a> {
         input_file_names: I,
         output_level: i32,
         output_path_id: i32,
+    ) -> Result<()> {
+        self.compact_files_to_cf(
+            compact_options,
+            &self.default_column_family(),
+            input_file_names,
+            output_level,
+            output_path_id,
+        )
+    }
+
+    /// Like `compact_files`, for the files of the given column family.
+    pub fn compact_files_cf<P: AsRef<Path>, I: IntoIterator<Item = P>>(
+        &self,
+        compact_options: &CompactionOptions,
+        column_family: &ColumnFamilyHandle,
+        input_file_names: I,
+        output_level: i32,
+    ) -> Result<()> {
+        self.compact_files_to_cf(compact_options, column_family, input_file_names, output_level, -1)
+    }
+
+    pub fn compact_files_to_cf<P: AsRef<Path>, I: IntoIterator<Item = P>>(
+        &self,
+        compact_options: &CompactionOptions,
+        column_family: &ColumnFamilyHandle,
+        input_file_names: I,
+        output_level: i32,
+        output_path_id: i32,
     ) -> Result<()> {
         let mut c_file_names = Vec::new();
         let mut c_file_name_sizes = Vec::new();
@@ -1718,9 +1892,10 @@ impl<'a> DBRef<'a> {
         }
         let mut status = ptr::null_mut();
         unsafe {
-            ll::rocks_db_compact_files(
+            ll::rocks_db_compact_files_cf(
                 self.raw(),
                 compact_options.raw(),
+                column_family.raw(),
                 c_file_names.len(),
                 c_file_names.as_ptr(),
                 c_file_name_sizes.as_ptr(),
@@ -1808,9 +1983,21 @@ impl<'a> DBRef<'a> {
         name
     }
 
-    // TODO:
-    // get options
-    // get db options
+    /// Returns the options of the default column family, reflecting changes
+    /// made with `set_options`.
+    pub fn get_options(&self) -> ColumnFamilyOptions {
+        self.get_options_cf(&self.default_column_family())
+    }
+
+    pub fn get_options_cf(&self, column_family: &ColumnFamilyHandle) -> ColumnFamilyOptions {
+        unsafe { ColumnFamilyOptions::from_ll(ll::rocks_db_get_options(self.raw(), column_family.raw())) }
+    }
+
+    /// Returns the DB options in use, reflecting changes made with
+    /// `set_db_options`.
+    pub fn get_db_options(&self) -> DBOptions {
+        unsafe { DBOptions::from_ll(ll::rocks_db_get_db_options(self.raw())) }
+    }
 
     /// Flush all mem-table data.
     pub fn flush(&self, options: &FlushOptions) -> Result<()> {
@@ -1821,6 +2008,30 @@ impl<'a> DBRef<'a> {
         }
     }
 
+    /// Flush all mem-table data of the column family.
+    ///
+    /// Each call flushes a single column family, flushing several of them
+    /// atomically is not supported by RocksDB 5.8.
+    pub fn flush_cf(&self, options: &FlushOptions, column_family: &ColumnFamilyHandle) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_db_flush_cf(self.raw(), options.raw(), column_family.raw(), &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// Write the WAL buffer to the log file, syncing it too if `sync` is true.
+    ///
+    /// Only needed when `DBOptions::manual_wal_flush` is set, otherwise the
+    /// buffer is flushed after each write.
+    pub fn flush_wal(&self, sync: bool) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_db_flush_wal(self.raw(), sync as u8, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
     /// Sync the wal. Note that Write() followed by SyncWAL() is not exactly the
     /// same as Write() with sync=true: in the latter case the changes won't be
     /// visible until the sync is done.
@@ -1956,6 +2167,50 @@ impl<'a> DBRef<'a> {
         }
     }
 
+    /// Collects up to `max_batches` write batches starting at the one containing
+    /// `since`, and returns them together with the sequence number to resume from.
+    ///
+    /// Passing the returned sequence number to the next call continues right after
+    /// the last collected batch, without gaps or duplicates.
+    ///
+    /// Rust: convenience wrapper around `get_updates_since`.
+    pub fn drain_updates_since(
+        &self,
+        since: SequenceNumber,
+        max_batches: usize,
+    ) -> Result<(Vec<(SequenceNumber, WriteBatch)>, SequenceNumber)> {
+        let mut batches = Vec::new();
+        let mut next_seq = since;
+        if max_batches == 0 || since.0 > self.get_latest_sequence_number().0 {
+            return Ok((batches, next_seq));
+        }
+        let mut it = try!(self.get_updates_since(since));
+        while batches.len() < max_batches && it.is_valid() {
+            try!(it.status());
+            let BatchResult { sequence, write_batch } = it.get_batch();
+            next_seq = SequenceNumber(sequence.0 + write_batch.count() as u64);
+            batches.push((sequence, write_batch));
+            it.move_next();
+        }
+        // a corrupted or truncated WAL also ends the iterator
+        if !it.is_valid() {
+            try!(it.status());
+        }
+        Ok((batches, next_seq))
+    }
+
+    /// Tails the WAL from the batch containing `since`, yielding each write batch
+    /// with its sequence number until no more records are available.
+    ///
+    /// Rust: convenience wrapper around `get_updates_since`, starting past the
+    /// latest sequence number yields nothing instead of an error.
+    pub fn tail_wal_from(&self, since: SequenceNumber) -> Result<WalTail> {
+        if since.0 > self.get_latest_sequence_number().0 {
+            return Ok(WalTail::default());
+        }
+        self.get_updates_since(since).map(WalTail::new)
+    }
+
     /// Delete the file name from the db directory and update the internal state to
     /// reflect that. Supports deletion of sst and log files only. 'name' must be
     /// path relative to the db directory. eg. 000001.sst, /archive/000003.log
@@ -1973,16 +2228,45 @@ impl<'a> DBRef<'a> {
     /// entirely in the range.
     ///
     /// Snapshots before the delete might not see the data in the given range.
-    pub fn delete_files_in_range(&self, column_family: &ColumnFamilyHandle, begin: &[u8], end: &[u8]) -> Result<()> {
+    ///
+    /// Files in level 0 are never deleted.
+    ///
+    /// For Rust: use range expr, `..` deletes all files of the column family. Files whose
+    /// largest key equals the range end are deleted too, as RocksDB 5.8 always includes the end.
+    pub fn delete_files_in_range<R: ToCompactRange>(&self, column_family: &ColumnFamilyHandle, range: R) -> Result<()> {
         let mut status = ptr::null_mut::<ll::rocks_status_t>();
         unsafe {
             ll::rocks_db_delete_files_in_range(
                 self.raw(),
                 column_family.raw(),
-                begin.as_ptr() as *const _,
-                begin.len(),
-                end.as_ptr() as *const _,
-                end.len(),
+                range.start_key() as *const _,
+                range.start_key_len(),
+                range.end_key() as *const _,
+                range.end_key_len(),
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+
+    /// Mark the files overlapping the given range for compaction, and let the
+    /// background compaction threads pick them up.
+    ///
+    /// Unlike `compact_range`, this returns immediately. Files marked are
+    /// only compacted when auto compactions are enabled, and files in the last
+    /// non-empty level are never marked.
+    ///
+    /// For Rust: use range expr, `..` suggests the whole column family.
+    pub fn suggest_compact_range<R: ToCompactRange>(&self, column_family: &ColumnFamilyHandle, range: R) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_db_suggest_compact_range(
+                self.raw(),
+                column_family.raw(),
+                range.start_key() as *const _,
+                range.start_key_len(),
+                range.end_key() as *const _,
+                range.end_key_len(),
                 &mut status,
             );
             Status::from_ll(status)
@@ -2217,10 +2501,15 @@ impl<'a> DBRef<'a> {
         ColumnFamily {
             handle: ColumnFamilyHandle { raw: unsafe { ll::rocks_db_default_column_family(self.raw()) } },
             db: self.borrow(),
-            owned: false,
         }
     }
 
+    /// Returns the table properties of every live SST file of the default
+    /// column family, keyed by file path.
+    pub fn get_properties_of_all_tables(&self) -> Result<TablePropertiesCollection> {
+        self.get_properties_of_all_tables_cf(&self.default_column_family())
+    }
+
     pub fn get_properties_of_all_tables_cf(
         &self,
         column_family: &ColumnFamilyHandle,
@@ -2451,20 +2740,27 @@ fn it_works() {
 }
 
 #[test]
-fn test_open_for_readonly() {
+fn test_open_for_read_only() {
     use tempdir::TempDir;
 
     let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
     let path = tmp_dir.path().to_str().unwrap();
 
-    {
-        let opt = Options::default().map_db_options(|opt| opt.create_if_missing(true));
-        let db = DB::open(&opt, path);
-        assert!(db.is_ok());
-    }
+    let opt = Options::default().map_db_options(|opt| opt.create_if_missing(true));
+    let writer = DB::open(&opt, path).unwrap();
+    assert!(writer.put(&WriteOptions::default(), b"name", b"BH1XUW").is_ok());
+    assert!(writer.flush(&FlushOptions::default().wait(true)).is_ok());
+
+    // does not conflict with the writer's LOCK
+    let db = DB::open_for_read_only(&Options::default(), path, false);
+    assert!(db.is_ok(), "err => {:?}", db);
+    let db = db.unwrap();
 
-    let db = DB::open_for_readonly(&Options::default(), path, false);
-    assert!(db.is_ok());
+    assert_eq!(db.get(&ReadOptions::default(), b"name").unwrap(), b"BH1XUW");
+
+    let ret = db.put(&WriteOptions::default(), b"name", b"another");
+    assert!(ret.is_err());
+    assert_eq!(ret.unwrap_err().code(), ::error::Code::NotSupported);
 }
 
 
@@ -2494,6 +2790,10 @@ fn test_list_cfs() {
     assert!(ret.as_ref().unwrap().contains(&"default".to_owned()));
     assert!(ret.as_ref().unwrap().contains(&"cf1".to_owned()));
     assert!(ret.as_ref().unwrap().contains(&"cf2".to_owned()));
+    assert_eq!(ret.as_ref().unwrap().len(), 3);
+
+    // works without opening, fails if there is no DB at all
+    assert!(DB::list_column_families(&opt, tmp_dir.path().join("not-a-db")).is_err());
 
     let cfs = ret.unwrap();
     if let Ok((db, cf_handles)) = DB::open_with_column_families(&Options::default(), path, cfs) {
@@ -2503,6 +2803,127 @@ fn test_list_cfs() {
 
 }
 
+#[test]
+fn test_create_and_drop_cf() {
+    use tempdir::TempDir;
+
+    let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
+
+    {
+        let opt = Options::default().map_db_options(|opt| opt.create_if_missing(true));
+        let db = DB::open(&opt, &tmp_dir).unwrap();
+
+        let cf = db.create_column_family(&ColumnFamilyOptions::default(), "tenant1").unwrap();
+        assert!(cf.put(&WriteOptions::default(), b"name", b"rocks").is_ok());
+        assert_eq!(cf.get(&ReadOptions::default(), b"name").unwrap(), b"rocks".as_ref());
+        // same name can't be created twice
+        assert!(db.create_column_family(&ColumnFamilyOptions::default(), "tenant1").is_err());
+
+        let cfs = DB::list_column_families(&Options::default(), &tmp_dir).unwrap();
+        assert!(cfs.contains(&"tenant1".to_owned()));
+
+        assert!(db.drop_column_family(&cf).is_ok());
+        // default column family can't be dropped
+        assert!(db.drop_column_family(&db.default_column_family()).is_err());
+    }
+
+    let cfs = DB::list_column_families(&Options::default(), &tmp_dir).unwrap();
+    assert_eq!(cfs, vec!["default".to_owned()]);
+
+    let ret = DB::open_with_column_families(&Options::default(), &tmp_dir, vec!["default", "tenant1"]);
+    assert!(ret.is_err());
+}
+
+#[test]
+fn test_compact_range_cf() {
+    use tempdir::TempDir;
+
+    let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
+    let opt = Options::default()
+        .map_db_options(|opt| opt.create_if_missing(true).create_missing_column_families(true))
+        .map_cf_options(|cf| cf.disable_auto_compactions(true));
+
+    // each reopen flushes the recovered memtables into a new L0 file
+    for i in 0..3 {
+        let (db, cfs) = DB::open_with_column_families(&opt, &tmp_dir, vec!["default", "cf1", "cf2"]).unwrap();
+        for cf in &cfs[1..] {
+            for j in 0..10 {
+                let key = format!("k{}", j);
+                let val = format!("v{}", i);
+                assert!(db.put_cf(&WriteOptions::default(), cf, key.as_bytes(), val.as_bytes()).is_ok());
+            }
+        }
+    }
+
+    let (db, cfs) = DB::open_with_column_families(&opt, &tmp_dir, vec!["default", "cf1", "cf2"]).unwrap();
+    let cf1_files = db.get_column_family_metadata(&cfs[1]).file_count;
+    let cf2_files = db.get_column_family_metadata(&cfs[2]).file_count;
+    assert!(cf1_files >= 3);
+    assert!(cf2_files >= 3);
+
+    assert!(db.compact_range_cf(&CompactRangeOptions::default(), &cfs[1], ..).is_ok());
+
+    assert_eq!(db.get_column_family_metadata(&cfs[1]).file_count, 1);
+    assert_eq!(db.get_column_family_metadata(&cfs[2]).file_count, cf2_files);
+    assert_eq!(cfs[1].get(&ReadOptions::default(), b"k5").unwrap(), b"v2".as_ref());
+}
+
+#[test]
+fn test_cf_handle() {
+    use tempdir::TempDir;
+
+    let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
+    let opt = Options::default().map_db_options(|opt| opt.create_if_missing(true).create_missing_column_families(true));
+    let (db, cfs) = DB::open_with_column_families(&opt, &tmp_dir, vec!["default", "cf1", "cf2"]).unwrap();
+
+    let cf1 = db.cf_handle("cf1").unwrap();
+    let cf2 = db.cf_handle("cf2").unwrap();
+    assert_eq!(cf1.name(), "cf1");
+    assert_eq!(cf1, cf1);
+    assert_eq!(cf2, cf2);
+    assert!(cf1 != cf2);
+    assert_eq!(cf1, &*cfs[1]);
+    assert_eq!(cf2, &*cfs[2]);
+    assert!(db.cf_handle("cf3").is_none());
+
+    assert!(db.put_cf(&WriteOptions::default(), cf1, b"name", b"rocks").is_ok());
+    assert_eq!(cfs[1].get(&ReadOptions::default(), b"name").unwrap(), b"rocks".as_ref());
+
+    let cf3 = db.create_column_family(&ColumnFamilyOptions::default(), "cf3").unwrap();
+    assert_eq!(db.cf_handle("cf3"), Some(&*cf3));
+    // handle stays valid after the ColumnFamily is dropped
+    drop(cf3);
+    assert!(db.put_cf(&WriteOptions::default(), db.cf_handle("cf3").unwrap(), b"k", b"v").is_ok());
+}
+
+#[test]
+fn test_drop_column_family() {
+    use tempdir::TempDir;
+
+    let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
+    let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+
+    // create/drop churn with the same name
+    for _ in 0..3 {
+        let cf = db.create_column_family(&ColumnFamilyOptions::default(), "x").unwrap();
+        assert_eq!(db.cf_handle("x"), Some(&*cf));
+        assert!(db.drop_column_family(&cf).is_ok());
+        assert!(db.cf_handle("x").is_none());
+    }
+
+    // a handle from cf_handle outlives the drop of its column family
+    let _cf = db.create_column_family(&ColumnFamilyOptions::default(), "y").unwrap();
+    let handle = db.cf_handle("y").unwrap();
+    assert!(db.drop_column_family(handle).is_ok());
+    assert_eq!(handle.name(), "y");
+    assert!(db.cf_handle("y").is_none());
+    assert!(db.drop_column_family(handle).is_err());
+    assert_eq!(
+        DB::list_column_families(&Options::default(), &tmp_dir).unwrap(),
+        vec!["default".to_string()]
+    );
+}
+
 #[test]
 fn test_db_get() {
     use tempdir::TempDir;
@@ -2687,6 +3108,76 @@ mod tests {
         assert!(ret[5].as_ref().unwrap_err().is_not_found());
     }
 
+    #[test]
+    fn get_pinned_into() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+
+        for i in 0..100 {
+            let key = format!("key-{:03}", i);
+            let value = "v".repeat(i + 1);
+            assert!(db.put(&Default::default(), key.as_bytes(), value.as_bytes()).is_ok());
+            if i == 50 {
+                // half of the values are read from sst files
+                assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            }
+        }
+
+        let mut value = PinnableSlice::new();
+        for i in 0..100 {
+            let key = format!("key-{:03}", i);
+            assert_eq!(db.get_pinned_into(&ReadOptions::default(), key.as_bytes(), &mut value), Ok(true));
+            assert_eq!(value, "v".repeat(i + 1).as_bytes());
+
+            // absent keys clear the slice
+            let key = format!("key-{:03}-absent", i);
+            assert_eq!(db.get_pinned_into(&ReadOptions::default(), key.as_bytes(), &mut value), Ok(false));
+            assert!(value.is_empty());
+        }
+    }
+
+    #[test]
+    fn write_and_get_seq() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+
+        let mut batch = WriteBatch::new();
+        batch.put(b"a", b"1").put(b"b", b"2");
+        let first = db.write_and_get_seq(&WriteOptions::default(), batch).unwrap();
+        assert_eq!(first, SequenceNumber(2));
+
+        let mut batch = WriteBatch::new();
+        batch.delete(b"a").put(b"c", b"3").put(b"d", b"4");
+        let second = db.write_and_get_seq(&WriteOptions::default(), batch).unwrap();
+        assert!(second.0 > first.0);
+        assert_eq!(second.0, first.0 + 3);
+        assert_eq!(db.get_latest_sequence_number(), second);
+    }
+
+    #[test]
+    fn multi_get_pinned() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+
+        assert!(db.put(&Default::default(), b"a", b"1").is_ok());
+        assert!(db.put(&Default::default(), b"b", b"2").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(db.put(&Default::default(), b"long-key", b"long-value").is_ok());
+        assert!(db.delete(&Default::default(), b"b").is_ok());
+
+        let snapshot = db.get_snapshot();
+        let ret = db.multi_get_pinned(
+            &ReadOptions::default().snapshot(snapshot.as_ref()),
+            &[b"a", b"b", b"long-key", b"non-exist", b"a"],
+        );
+        assert_eq!(ret.len(), 5);
+        assert_eq!(ret[0].as_ref().unwrap().as_ref().unwrap(), b"1".as_ref());
+        assert!(ret[1].as_ref().unwrap().is_none());
+        assert_eq!(ret[2].as_ref().unwrap().as_ref().unwrap(), b"long-value".as_ref());
+        assert!(ret[3].as_ref().unwrap().is_none());
+        assert_eq!(ret[4].as_ref().unwrap().as_ref().unwrap(), b"1".as_ref());
+    }
+
     #[test]
     fn multi_get_cf() {
         let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
@@ -2922,6 +3413,195 @@ mod tests {
         assert_eq!(*db.get_latest_sequence_number(), 4);
     }
 
+    #[test]
+    fn get_options_after_set_options() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true).max_background_jobs(3))
+                .map_cf_options(|cf| cf.write_buffer_size(1 << 20)),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.get_options().to_string().contains("write_buffer_size=1048576;"));
+        assert!(db.get_db_options().to_string().contains("max_background_jobs=3;"));
+
+        let mut new_options = HashMap::new();
+        new_options.insert("write_buffer_size", "2097152");
+        assert!(db.set_options(&new_options).is_ok());
+        let mut new_options = HashMap::new();
+        new_options.insert("max_background_jobs", "5");
+        assert!(db.set_db_options(&new_options).is_ok());
+
+        let cf_opts = db.get_options_cf(&db.default_column_family()).to_string();
+        assert!(cf_opts.contains("write_buffer_size=2097152;"), "got {}", cf_opts);
+        assert!(db.get_db_options().to_string().contains("max_background_jobs=5;"));
+    }
+
+    #[test]
+    fn get_latest_sequence_number() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+
+            let seq = db.get_latest_sequence_number();
+            assert!(db.put(&Default::default(), b"a", b"1").is_ok());
+            let after_put = db.get_latest_sequence_number();
+            assert!(after_put.0 > seq.0);
+
+            assert!(db.delete(&Default::default(), b"a").is_ok());
+            let after_delete = db.get_latest_sequence_number();
+            assert!(after_delete.0 > after_put.0);
+
+            // reads don't consume sequence numbers
+            assert!(db.get(&Default::default(), b"a").is_err());
+            assert_eq!(db.get_latest_sequence_number(), after_delete);
+        }
+
+        // recovered from the WAL
+        let db = DB::open(Options::default(), &tmp_dir).unwrap();
+        assert_eq!(*db.get_latest_sequence_number(), 2);
+    }
+
+    #[test]
+    fn db_identity_stable_across_reopen() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let path = tmp_dir.path().to_str().unwrap();
+        let identity = {
+            let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), path).unwrap();
+            assert_eq!(db.name(), path);
+            db.get_db_identity().unwrap()
+        };
+        assert!(!identity.is_empty());
+
+        let db = DB::open(&Options::default(), path).unwrap();
+        assert_eq!(db.get_db_identity().unwrap(), identity);
+
+        let other_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let other = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &other_dir).unwrap();
+        assert!(other.get_db_identity().unwrap() != identity);
+    }
+
+    #[test]
+    fn pause_background_work() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"k1", b"v1").is_ok());
+
+        assert!(db.pause_background_work().is_ok());
+        // memtable is switched, but the flush job is only queued
+        assert!(db.flush(&FlushOptions::default().wait(false)).is_ok());
+        assert_eq!(db.get_int_property("rocksdb.num-immutable-mem-table"), Some(1));
+        assert_eq!(db.get_property("rocksdb.num-files-at-level0"), Some("0".to_string()));
+
+        assert!(db.continue_background_work().is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert_eq!(db.get_int_property("rocksdb.num-immutable-mem-table"), Some(0));
+        assert_eq!(db.get_property("rocksdb.num-files-at-level0"), Some("1".to_string()));
+        assert_eq!(db.get(&ReadOptions::default(), b"k1").unwrap(), b"v1".as_ref());
+    }
+
+    #[test]
+    fn disable_file_deletions() {
+        use std::fs;
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.disable_auto_compactions(true)),
+            &tmp_dir,
+        ).unwrap();
+        let sst_files_on_disk = || {
+            fs::read_dir(&tmp_dir)
+                .unwrap()
+                .filter(|e| e.as_ref().unwrap().file_name().to_str().unwrap().ends_with(".sst"))
+                .count()
+        };
+
+        for i in 0..4 {
+            let key = format!("k{}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"v").is_ok());
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+        assert_eq!(sst_files_on_disk(), 4);
+
+        // balanced, deletions stay disabled until the second enable
+        assert!(db.disable_file_deletions().is_ok());
+        assert!(db.disable_file_deletions().is_ok());
+
+        // 4 input files become obsolete
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert_eq!(db.get_column_family_metadata(&db.default_column_family()).file_count, 1);
+        assert_eq!(sst_files_on_disk(), 5);
+
+        assert!(db.enable_file_deletions(false).is_ok());
+        assert_eq!(sst_files_on_disk(), 5);
+        assert!(db.enable_file_deletions(false).is_ok());
+        assert_eq!(sst_files_on_disk(), 1);
+
+        // force enables regardless of the number of disable calls
+        assert!(db.disable_file_deletions().is_ok());
+        assert!(db.disable_file_deletions().is_ok());
+        assert!(db.enable_file_deletions(true).is_ok());
+        assert!(db.put(&WriteOptions::default(), b"k4", b"v").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert_eq!(sst_files_on_disk(), 1);
+    }
+
+    #[test]
+    fn flush_cf() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.disable_auto_compactions(true)),
+            &tmp_dir,
+        ).unwrap();
+        let cf1 = db.create_column_family(&ColumnFamilyOptions::default(), "cf1").unwrap();
+        let cf2 = db.create_column_family(&ColumnFamilyOptions::default(), "cf2").unwrap();
+
+        assert!(db.put_cf(&WriteOptions::default(), &cf1, b"a", b"1").is_ok());
+        assert!(db.put_cf(&WriteOptions::default(), &cf2, b"b", b"2").is_ok());
+
+        let flush_opts = FlushOptions::default().wait(true);
+        assert!(db.flush_cf(&flush_opts, &cf1).is_ok());
+        assert_eq!(db.get_column_family_metadata(&cf1).file_count, 1);
+        // untouched
+        assert_eq!(db.get_column_family_metadata(&cf2).file_count, 0);
+
+        assert!(db.flush_cf(&flush_opts, &cf2).is_ok());
+        assert_eq!(db.get_column_family_metadata(&cf2).file_count, 1);
+        assert_eq!(db.get_column_family_metadata(&db.default_column_family()).file_count, 0);
+    }
+
+    #[test]
+    fn flush_wal() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opts = Options::default().map_db_options(|db| db.create_if_missing(true).manual_wal_flush(true));
+        {
+            let db = DB::open(&opts, &tmp_dir).unwrap();
+            for i in 0..10 {
+                let key = format!("k{}", i);
+                assert!(db.put(&WriteOptions::default().sync(false), key.as_bytes(), b"v").is_ok());
+            }
+            assert!(db.flush_wal(true).is_ok());
+
+            assert!(db.put(&WriteOptions::default().sync(false), b"k10", b"v").is_ok());
+            assert!(db.flush_wal(false).is_ok());
+            assert!(db.sync_wal().is_ok());
+        }
+
+        // recovered from WAL, nothing was flushed to SST
+        let db = DB::open(&opts, &tmp_dir).unwrap();
+        for i in 0..11 {
+            let key = format!("k{}", i);
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), b"v".as_ref());
+        }
+    }
+
     #[test]
     fn livemetadata() {
         let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
@@ -2998,6 +3678,52 @@ mod tests {
     }
 
 
+    #[test]
+    fn column_family_meta_level0_files() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.disable_auto_compactions(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        for prefix in &["a", "b", "c"] {
+            for i in 0..10 {
+                let key = format!("{}{}", prefix, i);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+
+        let meta = db.get_column_family_metadata(&db.default_column_family());
+        assert_eq!(meta.name, "default");
+        assert_eq!(meta.file_count, 3);
+        assert_eq!(meta.levels[0].level, 0);
+        assert_eq!(meta.levels[0].files.len(), 3);
+        assert!(meta.levels[1..].iter().all(|lv| lv.files.is_empty()));
+        assert_eq!(meta.size, meta.levels[0].files.iter().map(|f| f.size).sum::<u64>());
+
+        let mut bounds = meta.levels[0]
+            .files
+            .iter()
+            .map(|f| {
+                assert!(f.name.ends_with(".sst"));
+                assert!(f.smallest_seqno.0 <= f.largest_seqno.0);
+                (f.smallestkey.clone(), f.largestkey.clone())
+            })
+            .collect::<Vec<_>>();
+        bounds.sort();
+        assert_eq!(
+            bounds,
+            vec![
+                (b"a0".to_vec(), b"a9".to_vec()),
+                (b"b0".to_vec(), b"b9".to_vec()),
+                (b"c0".to_vec(), b"c9".to_vec()),
+            ]
+        );
+    }
+
     #[test]
     fn list_live_files() {
         let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
@@ -3014,7 +3740,10 @@ mod tests {
         assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
 
         if let Ok((size, files)) = db.get_live_files(false) {
+            assert!(size > 0, "valid manifest size");
             assert!(files.contains(&"/CURRENT".to_string()));
+            assert!(files.iter().any(|f| f.starts_with("/MANIFEST-")));
+            assert_eq!(files.iter().filter(|f| f.ends_with(".sst")).count(), 2);
         } else {
             assert!(false, "get_live_files fails");
         }
@@ -3041,7 +3770,11 @@ mod tests {
         }
         let files = db.get_sorted_wal_files();
         assert!(files.is_ok());
-        assert!(files.unwrap().len() > 2);
+        let files = files.unwrap();
+        assert!(files.len() > 2);
+        // earliest file first
+        assert!(files.windows(2).all(|w| w[0].log_number < w[1].log_number));
+        assert!(files.windows(2).all(|w| w[0].start_sequence.0 < w[1].start_sequence.0));
     }
 
     #[test]
@@ -3177,6 +3910,34 @@ mod tests {
         assert_eq!(result[0].level, 4); // compacted to 4
     }
 
+    #[test]
+    fn compact_files_cf() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        let cf = db.create_column_family(&ColumnFamilyOptions::default().disable_auto_compactions(true), "cf1")
+            .unwrap();
+
+        for i in 0..4 {
+            let key = format!("k{}", i);
+            assert!(db.put_cf(&WriteOptions::default(), &cf, key.as_bytes(), b"value").is_ok());
+            assert!(db.flush_cf(&FlushOptions::default().wait(true), &cf).is_ok());
+        }
+
+        let meta = db.get_column_family_metadata(&cf);
+        let level0_files = meta.levels[0].files.iter().map(|f| f.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(level0_files.len(), 4);
+
+        let compact_opts = CompactionOptions::default()
+            .compression(CompressionType::NoCompression)
+            .output_file_size_limit(64 << 20);
+        assert!(db.compact_files_cf(&compact_opts, &cf, &level0_files, 1).is_ok());
+
+        let meta = db.get_column_family_metadata(&cf);
+        assert!(meta.levels[0].files.is_empty());
+        assert_eq!(meta.levels[1].files.len(), 1);
+        assert_eq!(db.get_cf(&ReadOptions::default(), &cf, b"k3").unwrap().as_ref(), b"value");
+    }
+
     #[test]
     fn get_properties_of_all_tables() {
         let tmp_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
@@ -3228,9 +3989,38 @@ mod tests {
         assert!(vals.len() > 4);
     }
 
+    #[test]
+    fn get_properties_of_all_tables_default_column_family() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true)),
+            &tmp_dir,
+        ).unwrap();
+        let cf = db.create_column_family(&ColumnFamilyOptions::default(), "events").unwrap();
+
+        for round in 0..2 {
+            for i in 0..10 {
+                let key = format!("k{}-{}", round, i);
+                assert!(db.put(WriteOptions::default_instance(), key.as_bytes(), b"v").is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+
+        let props = db.get_properties_of_all_tables().unwrap();
+        assert!(props.len() >= 2);
+        for (file, prop) in props.iter() {
+            assert!(file.ends_with(".sst"));
+            assert_eq!(prop.column_family_name(), Some("default"));
+            assert!(prop.num_entries() > 0);
+        }
+
+        // nothing written to the other column family
+        assert_eq!(db.get_properties_of_all_tables_cf(&cf).unwrap().len(), 0);
+    }
+
     #[test]
     fn delete_files_in_range() {
-        let tmp_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
         let db = DB::open(
             Options::default().map_db_options(|db| db.create_if_missing(true)),
             // NOTE: delete_files_in_range() requires auto compaction
@@ -3253,7 +4043,7 @@ mod tests {
         let (_old_size, old_files) = db.get_live_files(false).expect("should get live files");
 
         assert!(
-            db.delete_files_in_range(&db.default_column_family(), b"k2", b"k8")
+            db.delete_files_in_range(&db.default_column_family(), b"k2".as_ref()..b"k8".as_ref())
                 .is_ok()
         );
 
@@ -3264,4 +4054,137 @@ mod tests {
             assert!(old_files.contains(f));
         }
     }
+
+    #[test]
+    fn suggest_compact_range() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.level0_file_num_compaction_trigger(100)), // never triggered by file count
+            &tmp_dir,
+        ).unwrap();
+
+        // files of the last non-empty level are never marked, move a base file below level 0
+        for key in &[b"a", b"m", b"z"] {
+            assert!(db.put(WriteOptions::default_instance(), key.as_ref(), b"base").is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert_eq!(db.get_property("rocksdb.num-files-at-level0"), Some("0".to_string()));
+
+        // overlapping level 0 files
+        for i in 0..3 {
+            for key in &[b"a", b"m", b"z"] {
+                let val = format!("v{}", i);
+                assert!(db.put(WriteOptions::default_instance(), key.as_ref(), val.as_bytes()).is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+        assert_eq!(db.get_property("rocksdb.num-files-at-level0"), Some("3".to_string()));
+
+        // schedules a background compaction of the marked files before returning
+        assert!(db.suggest_compact_range(&db.default_column_family(), b"a".as_ref()..b"z".as_ref()).is_ok());
+        // waits for all scheduled background jobs to finish
+        assert!(db.pause_background_work().is_ok());
+        let meta = db.get_column_family_metadata(&db.default_column_family());
+        assert!(meta.levels[0].files.is_empty(), "marked files are compacted in background");
+        assert!(db.continue_background_work().is_ok());
+
+        assert_eq!(db.get(&ReadOptions::default(), b"m").unwrap().as_ref(), b"v2");
+    }
+
+    #[test]
+    fn delete_files_in_open_range() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.disable_auto_compactions(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        // one sst file per key, moved out of level 0, whose files are never deleted
+        for i in 0..10 {
+            let key = format!("k{}", i);
+            assert!(db.put(WriteOptions::default_instance(), key.as_bytes(), b"v").is_ok());
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            assert!(
+                db.compact_range(&Default::default(), key.as_bytes()..key.as_bytes())
+                    .is_ok()
+            );
+        }
+        assert_eq!(db.get_property("rocksdb.num-files-at-level0"), Some("0".to_string()));
+        let num_sst_files = || {
+            db.get_live_files(false)
+                .unwrap()
+                .1
+                .iter()
+                .filter(|f| f.ends_with(".sst"))
+                .count()
+        };
+        assert_eq!(num_sst_files(), 10);
+
+        // k7, k8, k9
+        assert!(db.delete_files_in_range(&db.default_column_family(), b"k7".as_ref()..).is_ok());
+        assert_eq!(num_sst_files(), 7);
+        assert!(db.get(&ReadOptions::default(), b"k8").unwrap_err().is_not_found());
+        assert!(db.get(&ReadOptions::default(), b"k6").is_ok());
+
+        // k0, k1
+        assert!(db.delete_files_in_range(&db.default_column_family(), ..b"k1".as_ref()).is_ok());
+        assert_eq!(num_sst_files(), 5);
+        assert!(db.get(&ReadOptions::default(), b"k0").unwrap_err().is_not_found());
+
+        assert!(db.delete_files_in_range(&db.default_column_family(), ..).is_ok());
+        assert_eq!(num_sst_files(), 0);
+        assert_eq!(db.iter(&ReadOptions::default()).count(), 0);
+    }
+
+    #[test]
+    fn ingest_external_file_cf() {
+        use sst_file_writer::SstFileWriter;
+
+        let sst_dir = ::tempdir::TempDir::new_in(".", "rocks.sst").unwrap();
+
+        let writer = SstFileWriter::builder().build();
+        writer.open(sst_dir.path().join("0001.sst")).unwrap();
+        for i in 0..100 {
+            let key = format!("k{:03}", i);
+            let value = format!("v{:03}", i);
+            writer.put(key.as_bytes(), value.as_bytes()).unwrap();
+        }
+        assert!(writer.finish().is_ok());
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        let cf = db.create_column_family(&Default::default(), "bulk").unwrap();
+
+        // overlaps with the file key range, and must not be shadowed by a global seqno
+        assert!(cf.put(&WriteOptions::default(), b"k050", b"old").is_ok());
+
+        let ret = db.ingest_external_file_cf(
+            &cf,
+            &[sst_dir.path().join("0001.sst")],
+            &IngestExternalFileOptions::default().allow_global_seqno(false),
+        );
+        assert!(ret.is_err(), "overlapping file should not be ingested");
+        assert_eq!(ret.unwrap_err().code(), ::error::Code::InvalidArgument);
+
+        let ret = db.ingest_external_file_cf(
+            &cf,
+            &[sst_dir.path().join("0001.sst")],
+            &IngestExternalFileOptions::default()
+                .move_files(true)
+                .snapshot_consistency(true)
+                .allow_global_seqno(true),
+        );
+        assert!(ret.is_ok(), "ingest external file: {:?}", ret);
+
+        assert_eq!(cf.get(&ReadOptions::default(), b"k000").unwrap(), b"v000");
+        assert_eq!(cf.get(&ReadOptions::default(), b"k050").unwrap(), b"v050");
+        assert_eq!(cf.get(&ReadOptions::default(), b"k099").unwrap(), b"v099");
+        // ingested into `bulk` only
+        assert!(db.get(&ReadOptions::default(), b"k000").unwrap_err().is_not_found());
+    }
 }
@@ -139,7 +139,7 @@ mod tests {
 
         {
             let opt = Options::default();
-            let db = DB::open_for_readonly(&opt, &tmp_dir2, false).unwrap();
+            let db = DB::open_for_read_only(&opt, &tmp_dir2, false).unwrap();
 
             assert_eq!(db.get(&Default::default(), b"key_").as_ref().unwrap(), b"BY1CQ".as_ref());
         }
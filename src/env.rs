@@ -188,6 +188,18 @@ pub enum InfoLogLevel {
     Header,
 }
 
+/// Rust implemented log sink, wrapped as a `Logger` by `Logger::new`.
+///
+/// Messages arrive already formatted, and only those at or above the
+/// logger's log level are passed in.
+pub trait Log: Send + Sync {
+    /// Write an entry with the specified log level.
+    fn logv(&self, log_level: InfoLogLevel, msg: &str);
+
+    /// Flush to the underlying storage, if any.
+    fn flush(&self) {}
+}
+
 /// An interface for writing log messages.
 #[derive(Debug)]
 pub struct Logger {
@@ -213,6 +225,18 @@ impl Logger {
         Logger { raw: raw }
     }
 
+    /// Create a logger routing messages to a Rust `Log`, the log level
+    /// defaults to `InfoLogLevel::Info`.
+    ///
+    /// When used as `DBOptions::info_log`, its level is replaced by
+    /// `DBOptions::info_log_level`.
+    pub fn new(log: Box<Log>) -> Logger {
+        unsafe {
+            let raw_ptr = Box::into_raw(Box::new(log)); // Box<Box<Log>>
+            Logger::from_ll(ll::rocks_logger_create_by_trait(raw_ptr as *mut _))
+        }
+    }
+
     /// Write an entry to the log file with the specified log level
     /// and format.  Any log with level under the internal log level
     /// of *this (see @SetInfoLogLevel and @GetInfoLogLevel) will not be
@@ -241,6 +265,105 @@ impl Logger {
     }
 }
 
+/// A file abstraction for reading sequentially through a file
+pub trait SequentialFile: Send {
+    /// Read up to `buf.len()` bytes from the file, returns the number of
+    /// bytes read. Returning less than `buf.len()` means the end of file.
+    ///
+    /// REQUIRES: External synchronization
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+    /// Skip `n` bytes from the file. This is guaranteed to be no
+    /// slower that reading the same data, but may be faster.
+    ///
+    /// If end of file is reached, skipping will stop at the end of the
+    /// file, and Skip will return OK.
+    ///
+    /// REQUIRES: External synchronization
+    fn skip(&mut self, n: u64) -> Result<()>;
+}
+
+/// A file abstraction for randomly reading the contents of a file.
+pub trait RandomAccessFile: Send + Sync {
+    /// Read up to `buf.len()` bytes from the file starting at `offset`,
+    /// returns the number of bytes read.
+    ///
+    /// Safe for concurrent use by multiple threads.
+    fn read(&self, offset: u64, buf: &mut [u8]) -> Result<usize>;
+}
+
+/// A file abstraction for sequential writing.  The implementation
+/// must provide buffering since callers may append small fragments
+/// at a time to the file.
+pub trait WritableFile: Send {
+    fn append(&mut self, data: &[u8]) -> Result<()>;
+
+    fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// sync data
+    fn sync(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// File system operations of an `Env`, implemented in Rust.
+///
+/// Used by `Env::new_custom`, e.g. for an in-memory or remote file system.
+/// Other functionality (thread pools, clock, ...) is delegated to the default
+/// environment. Files are not locked, and the info log is disabled unless
+/// `DBOptions::info_log` is set.
+///
+/// Errors returned should use proper status codes, see `Status::with_code`.
+pub trait FileSystem: Send + Sync {
+    /// Create an object that sequentially reads the file with the specified name.
+    /// If the file does not exist, returns a non-OK status.
+    fn new_sequential_file(&self, fname: &str) -> Result<Box<SequentialFile>>;
+
+    /// Create an object supporting random-access reads from the file with the
+    /// specified name. If the file does not exist, returns a non-OK status.
+    fn new_random_access_file(&self, fname: &str) -> Result<Box<RandomAccessFile>>;
+
+    /// Create an object that writes to a new file with the specified
+    /// name.  Deletes any existing file with the same name and creates a
+    /// new file.
+    fn new_writable_file(&self, fname: &str) -> Result<Box<WritableFile>>;
+
+    /// Returns true if the named file exists.
+    fn file_exists(&self, fname: &str) -> bool;
+
+    /// Returns the names of the children of the specified directory.
+    /// The names are relative to `dir`.
+    fn get_children(&self, dir: &str) -> Result<Vec<String>>;
+
+    /// Delete the named file.
+    fn delete_file(&self, fname: &str) -> Result<()>;
+
+    /// Create the specified directory. Returns error if directory exists.
+    fn create_dir(&self, dirname: &str) -> Result<()>;
+
+    /// Creates directory if missing. Return Ok if it exists, or successful in
+    /// Creating.
+    fn create_dir_if_missing(&self, dirname: &str) -> Result<()>;
+
+    /// Delete the specified directory.
+    fn delete_dir(&self, dirname: &str) -> Result<()>;
+
+    /// Return the size of `fname`.
+    fn get_file_size(&self, fname: &str) -> Result<u64>;
+
+    /// Return the last modification time of `fname`, in seconds since the Epoch.
+    fn get_file_modification_time(&self, fname: &str) -> Result<u64>;
+
+    /// Rename file `src` to `target`.
+    fn rename_file(&self, src: &str, target: &str) -> Result<()>;
+}
+
 /// An `Env` is an interface used by the rocksdb implementation to access
 /// operating system functionality like the filesystem etc.
 pub struct Env {
@@ -292,6 +415,13 @@ impl Env {
         Env { raw: unsafe { ll::rocks_create_timed_env() } }
     }
 
+    /// Returns a new environment that routes file system operations to `fs`
+    /// and delegates all other tasks to the default environment.
+    pub fn new_custom(fs: Box<FileSystem>) -> Env {
+        let raw_ptr = Box::into_raw(Box::new(fs)); // Box<Box<FileSystem>>
+        Env { raw: unsafe { ll::rocks_create_env_by_trait(raw_ptr as *mut _) } }
+    }
+
 
     /// The number of background worker threads of a specific thread pool
     pub fn set_low_priority_background_threads(&self, number: i32) {
@@ -444,6 +574,263 @@ impl Env {
 }
 
 
+// call rust fn in C
+#[doc(hidden)]
+pub mod c {
+    use std::slice;
+
+    use super::*;
+
+    unsafe fn to_str<'a>(p: *const u8, len: usize) -> &'a str {
+        str::from_utf8_unchecked(slice::from_raw_parts(p, len))
+    }
+
+    // the status is freed in C
+    fn into_raw_status(result: Result<()>) -> *mut ll::rocks_status_t {
+        match result {
+            Ok(()) => ptr::null_mut(),
+            Err(status) => {
+                let raw = status.raw();
+                mem::forget(status);
+                raw
+            }
+        }
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_new_sequential_file(
+        e: *mut (),
+        fname: *const u8,
+        fname_len: usize,
+        file: *mut *mut (),
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).new_sequential_file(to_str(fname, fname_len)).map(|f| {
+            *file = Box::into_raw(Box::new(f)) as *mut (); // Box<Box<SequentialFile>>
+        }))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_new_random_access_file(
+        e: *mut (),
+        fname: *const u8,
+        fname_len: usize,
+        file: *mut *mut (),
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).new_random_access_file(to_str(fname, fname_len)).map(|f| {
+            *file = Box::into_raw(Box::new(f)) as *mut (); // Box<Box<RandomAccessFile>>
+        }))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_new_writable_file(
+        e: *mut (),
+        fname: *const u8,
+        fname_len: usize,
+        file: *mut *mut (),
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).new_writable_file(to_str(fname, fname_len)).map(|f| {
+            *file = Box::into_raw(Box::new(f)) as *mut (); // Box<Box<WritableFile>>
+        }))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_file_exists(e: *mut (), fname: *const u8, fname_len: usize) -> u8 {
+        let fs = e as *mut Box<FileSystem>;
+        (*fs).file_exists(to_str(fname, fname_len)) as u8
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_get_children(
+        e: *mut (),
+        dir: *const u8,
+        dir_len: usize,
+        result: *mut ll::cxx_string_vector_t,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).get_children(to_str(dir, dir_len)).map(|children| for child in children {
+            ll::cxx_string_vector_push_back(result, child.as_ptr() as *const _, child.len());
+        }))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_delete_file(
+        e: *mut (),
+        fname: *const u8,
+        fname_len: usize,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).delete_file(to_str(fname, fname_len)))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_create_dir(
+        e: *mut (),
+        dirname: *const u8,
+        dirname_len: usize,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).create_dir(to_str(dirname, dirname_len)))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_create_dir_if_missing(
+        e: *mut (),
+        dirname: *const u8,
+        dirname_len: usize,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).create_dir_if_missing(to_str(dirname, dirname_len)))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_delete_dir(
+        e: *mut (),
+        dirname: *const u8,
+        dirname_len: usize,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).delete_dir(to_str(dirname, dirname_len)))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_get_file_size(
+        e: *mut (),
+        fname: *const u8,
+        fname_len: usize,
+        file_size: *mut u64,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).get_file_size(to_str(fname, fname_len)).map(|size| *file_size = size))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_get_file_modification_time(
+        e: *mut (),
+        fname: *const u8,
+        fname_len: usize,
+        file_mtime: *mut u64,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).get_file_modification_time(to_str(fname, fname_len)).map(|mtime| *file_mtime = mtime))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_rename_file(
+        e: *mut (),
+        src: *const u8,
+        src_len: usize,
+        target: *const u8,
+        target_len: usize,
+    ) -> *mut ll::rocks_status_t {
+        let fs = e as *mut Box<FileSystem>;
+        into_raw_status((*fs).rename_file(to_str(src, src_len), to_str(target, target_len)))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_env_drop(e: *mut ()) {
+        assert!(!e.is_null());
+        let fs = e as *mut Box<FileSystem>;
+        Box::from_raw(fs);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_sequential_file_read(
+        f: *mut (),
+        scratch: *mut u8,
+        n: usize,
+        read: *mut usize,
+    ) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<SequentialFile>;
+        into_raw_status((*file).read(slice::from_raw_parts_mut(scratch, n)).map(|len| *read = len))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_sequential_file_skip(f: *mut (), n: u64) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<SequentialFile>;
+        into_raw_status((*file).skip(n))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_sequential_file_drop(f: *mut ()) {
+        assert!(!f.is_null());
+        let file = f as *mut Box<SequentialFile>;
+        Box::from_raw(file);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_random_access_file_read(
+        f: *mut (),
+        offset: u64,
+        scratch: *mut u8,
+        n: usize,
+        read: *mut usize,
+    ) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<RandomAccessFile>;
+        into_raw_status((*file).read(offset, slice::from_raw_parts_mut(scratch, n)).map(|len| *read = len))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_random_access_file_drop(f: *mut ()) {
+        assert!(!f.is_null());
+        let file = f as *mut Box<RandomAccessFile>;
+        Box::from_raw(file);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_writable_file_append(f: *mut (), data: &&[u8]) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<WritableFile>;
+        into_raw_status((*file).append(data))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_writable_file_close(f: *mut ()) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<WritableFile>;
+        into_raw_status((*file).close())
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_writable_file_flush(f: *mut ()) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<WritableFile>;
+        into_raw_status((*file).flush())
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_writable_file_sync(f: *mut ()) -> *mut ll::rocks_status_t {
+        let file = f as *mut Box<WritableFile>;
+        into_raw_status((*file).sync())
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_writable_file_drop(f: *mut ()) {
+        assert!(!f.is_null());
+        let file = f as *mut Box<WritableFile>;
+        Box::from_raw(file);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_logger_logv(l: *mut (), log_level: InfoLogLevel, msg: *const u8, msg_len: usize) {
+        let log = l as *mut Box<Log>;
+        let msg = String::from_utf8_lossy(slice::from_raw_parts(msg, msg_len));
+        (*log).logv(log_level, &msg);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_logger_flush(l: *mut ()) {
+        let log = l as *mut Box<Log>;
+        (*log).flush();
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_logger_drop(l: *mut ()) {
+        assert!(!l.is_null());
+        let log = l as *mut Box<Log>;
+        Box::from_raw(log);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::File;
@@ -461,6 +848,32 @@ mod tests {
         assert!(env.time_to_string(env.get_current_time().unwrap()).len() > 10);
     }
 
+    #[test]
+    fn lower_thread_pool_io_priority() {
+        use super::super::rocksdb::*;
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let env = Env::default_instance();
+        env.lower_thread_pool_io_priority(Priority::Low);
+        env.lower_thread_pool_io_priority(Priority::High);
+
+        let db = DB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true).env(env)),
+            &tmp_dir,
+        ).unwrap();
+        for i in 0..3 {
+            for j in 0..100 {
+                let key = format!("k{}", j);
+                let val = format!("v{}", i);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), val.as_bytes()).is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+        // the niceness is not observable, compactions still run to completion
+        assert!(db.compact_range(&CompactRangeOptions::default(), ..).is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"k42").unwrap(), b"v2".as_ref());
+    }
+
     #[test]
     fn logger() {
         let log_dir = ::tempdir::TempDir::new_in(".", "log").unwrap();
@@ -487,4 +900,290 @@ mod tests {
         assert!(s.contains("[ERROR] test log message"));
         assert!(!s.contains("debug log message"));
     }
+
+    #[test]
+    fn custom_env_in_memory_file_system() {
+        use std::collections::{BTreeMap, BTreeSet};
+        use std::sync::{Arc, Mutex, RwLock};
+        use error::Code;
+        use super::super::rocksdb::*;
+
+        type Data = Arc<RwLock<Vec<u8>>>;
+
+        #[derive(Default, Clone)]
+        struct MemFileSystem {
+            files: Arc<Mutex<BTreeMap<String, Data>>>,
+            dirs: Arc<Mutex<BTreeSet<String>>>,
+        }
+
+        struct MemFile {
+            data: Data,
+            pos: usize,
+        }
+
+        impl SequentialFile for MemFile {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                let n = try!(RandomAccessFile::read(self, self.pos as u64, buf));
+                self.pos += n;
+                Ok(n)
+            }
+
+            fn skip(&mut self, n: u64) -> Result<()> {
+                self.pos = (self.pos + n as usize).min(self.data.read().unwrap().len());
+                Ok(())
+            }
+        }
+
+        impl RandomAccessFile for MemFile {
+            fn read(&self, offset: u64, buf: &mut [u8]) -> Result<usize> {
+                let data = self.data.read().unwrap();
+                let start = (offset as usize).min(data.len());
+                let n = buf.len().min(data.len() - start);
+                buf[..n].copy_from_slice(&data[start..start + n]);
+                Ok(n)
+            }
+        }
+
+        impl WritableFile for MemFile {
+            fn append(&mut self, data: &[u8]) -> Result<()> {
+                self.data.write().unwrap().extend_from_slice(data);
+                Ok(())
+            }
+        }
+
+        impl MemFileSystem {
+            fn open(&self, fname: &str) -> Result<MemFile> {
+                self.files
+                    .lock()
+                    .unwrap()
+                    .get(fname)
+                    .map(|data| MemFile { data: data.clone(), pos: 0 })
+                    .ok_or_else(|| Status::with_code(Code::NotFound, fname))
+            }
+        }
+
+        impl FileSystem for MemFileSystem {
+            fn new_sequential_file(&self, fname: &str) -> Result<Box<SequentialFile>> {
+                self.open(fname).map(|f| Box::new(f) as Box<SequentialFile>)
+            }
+
+            fn new_random_access_file(&self, fname: &str) -> Result<Box<RandomAccessFile>> {
+                self.open(fname).map(|f| Box::new(f) as Box<RandomAccessFile>)
+            }
+
+            fn new_writable_file(&self, fname: &str) -> Result<Box<WritableFile>> {
+                let data = Data::default();
+                self.files.lock().unwrap().insert(fname.to_owned(), data.clone());
+                Ok(Box::new(MemFile { data: data, pos: 0 }))
+            }
+
+            fn file_exists(&self, fname: &str) -> bool {
+                self.files.lock().unwrap().contains_key(fname) || self.dirs.lock().unwrap().contains(fname)
+            }
+
+            fn get_children(&self, dir: &str) -> Result<Vec<String>> {
+                let prefix = format!("{}/", dir);
+                Ok(
+                    self.files
+                        .lock()
+                        .unwrap()
+                        .keys()
+                        .filter(|name| name.starts_with(&prefix))
+                        .map(|name| name[prefix.len()..].to_owned())
+                        .collect(),
+                )
+            }
+
+            fn delete_file(&self, fname: &str) -> Result<()> {
+                self.files
+                    .lock()
+                    .unwrap()
+                    .remove(fname)
+                    .map(|_| ())
+                    .ok_or_else(|| Status::with_code(Code::NotFound, fname))
+            }
+
+            fn create_dir(&self, dirname: &str) -> Result<()> {
+                if self.dirs.lock().unwrap().insert(dirname.to_owned()) {
+                    Ok(())
+                } else {
+                    Err(Status::with_code(Code::IOError, "directory exists"))
+                }
+            }
+
+            fn create_dir_if_missing(&self, dirname: &str) -> Result<()> {
+                self.dirs.lock().unwrap().insert(dirname.to_owned());
+                Ok(())
+            }
+
+            fn delete_dir(&self, dirname: &str) -> Result<()> {
+                self.dirs.lock().unwrap().remove(dirname);
+                Ok(())
+            }
+
+            fn get_file_size(&self, fname: &str) -> Result<u64> {
+                self.open(fname).map(|f| f.data.read().unwrap().len() as u64)
+            }
+
+            fn get_file_modification_time(&self, fname: &str) -> Result<u64> {
+                self.open(fname).map(|_| 0)
+            }
+
+            fn rename_file(&self, src: &str, target: &str) -> Result<()> {
+                let mut files = self.files.lock().unwrap();
+                let data = try!(files.remove(src).ok_or_else(|| Status::with_code(Code::NotFound, src)));
+                files.insert(target.to_owned(), data);
+                Ok(())
+            }
+        }
+
+        let fs = MemFileSystem::default();
+        // DBOptions::env requires an Env living as long as the process
+        let env: &'static Env = unsafe { &*Box::into_raw(Box::new(Env::new_custom(Box::new(fs.clone())))) };
+
+        // never touches the disk
+        let path = "/rocks-in-memory-env/db";
+        let opts = Options::default().map_db_options(|db| db.create_if_missing(true).env(env));
+
+        {
+            let db = DB::open(&opts, path).unwrap();
+            for i in 0..100 {
+                let key = format!("key-{}", i);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            assert!(db.put(&WriteOptions::default(), b"in-wal", b"value").is_ok());
+        }
+        assert!(!Path::new(path).exists());
+
+        let names = fs.get_children(path).unwrap();
+        assert!(names.contains(&"CURRENT".to_owned()));
+        assert!(names.iter().any(|name| name.ends_with(".sst")));
+
+        // recover from the manifest, table files and WAL
+        let db = DB::open(&opts, path).unwrap();
+        assert_eq!(db.get(&ReadOptions::default(), b"key-42").unwrap(), b"value".as_ref());
+        assert_eq!(db.get(&ReadOptions::default(), b"in-wal").unwrap(), b"value".as_ref());
+        assert!(db.get(&ReadOptions::default(), b"key-100").unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn background_threads_for_concurrent_compactions() {
+        use super::super::rocksdb::*;
+
+        // the default env is shared by the whole process, put the pools back
+        // once done, declared before the DB so it is dropped after it
+        struct RestoreThreads(&'static Env, i32, i32);
+
+        impl Drop for RestoreThreads {
+            fn drop(&mut self) {
+                self.0.set_background_threads(self.1, Priority::Low);
+                self.0.set_background_threads(self.2, Priority::High);
+            }
+        }
+
+        let env = Env::default_instance();
+        let _restore = RestoreThreads(
+            env,
+            env.get_background_threads(Priority::Low),
+            env.get_background_threads(Priority::High),
+        );
+        env.set_background_threads(4, Priority::Low);
+        env.set_background_threads(2, Priority::High);
+        assert!(env.get_background_threads(Priority::Low) >= 4);
+        assert!(env.get_background_threads(Priority::High) >= 2);
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let cfopts = ColumnFamilyOptions::default()
+            .write_buffer_size(64 << 10)
+            .level0_file_num_compaction_trigger(2);
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true).env(env).max_background_jobs(6))
+                .map_cf_options(|_| cfopts.clone()),
+            &tmp_dir,
+        ).unwrap();
+        let cfs = (0..4)
+            .map(|i| db.create_column_family(&cfopts, &format!("cf{}", i)).unwrap())
+            .collect::<Vec<_>>();
+
+        // compactions of different column families can run in parallel
+        let value = vec![b'v'; 100];
+        for i in 0..20000 {
+            let key = format!("key-{:05}", i);
+            assert!(cfs[i % 4].put(&WriteOptions::default(), key.as_bytes(), &value).is_ok());
+        }
+        for cf in &cfs {
+            assert!(db.compact_range_cf(&Default::default(), cf, ..).is_ok());
+        }
+
+        for i in (0..20000).step_by(997) {
+            let key = format!("key-{:05}", i);
+            assert_eq!(cfs[i % 4].get(&ReadOptions::default(), key.as_bytes()).unwrap(), &value[..]);
+        }
+    }
+
+    #[test]
+    fn rust_logger_as_info_log() {
+        use std::sync::{Arc, Mutex};
+        use super::super::rocksdb::*;
+
+        struct VecLog {
+            lines: Arc<Mutex<Vec<(InfoLogLevel, String)>>>,
+        }
+
+        impl Log for VecLog {
+            fn logv(&self, log_level: InfoLogLevel, msg: &str) {
+                self.lines.lock().unwrap().push((log_level, msg.to_owned()));
+            }
+        }
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let mut logger = Logger::new(Box::new(VecLog { lines: lines.clone() }));
+        assert_eq!(logger.get_log_level(), InfoLogLevel::Info);
+        logger.set_log_level(InfoLogLevel::Warn);
+        logger.log(InfoLogLevel::Error, "test log message");
+        logger.log(InfoLogLevel::Info, "info log message");
+        assert_eq!(*lines.lock().unwrap(), vec![(InfoLogLevel::Error, "test log message".to_owned())]);
+
+        logger.set_log_level(InfoLogLevel::Info);
+        lines.lock().unwrap().clear();
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(
+                Options::default().map_db_options(|db| {
+                    db.create_if_missing(true)
+                        .info_log(Some(logger))
+                        .info_log_level(InfoLogLevel::Info)
+                }),
+                &tmp_dir,
+            ).unwrap();
+            assert!(db.put(&WriteOptions::default(), b"key", b"value").is_ok());
+        }
+
+        {
+            let lines = lines.lock().unwrap();
+            assert!(!lines.is_empty());
+            assert!(lines.iter().any(|&(_, ref msg)| msg.contains("RocksDB version")));
+            assert!(lines.iter().all(|&(level, _)| level != InfoLogLevel::Debug));
+        }
+
+        // info_log_level is applied whichever setter comes first
+        lines.lock().unwrap().clear();
+        let logger = Logger::new(Box::new(VecLog { lines: lines.clone() }));
+        {
+            let _db = DB::open(
+                Options::default().map_db_options(|db| db.info_log_level(InfoLogLevel::Warn).info_log(Some(logger))),
+                &tmp_dir,
+            ).unwrap();
+        }
+        // header lines pass any level, so the logger was installed, while info lines are dropped
+        let lines = lines.lock().unwrap();
+        assert!(
+            lines
+                .iter()
+                .any(|&(level, ref msg)| level == InfoLogLevel::Header && msg.contains("RocksDB version"))
+        );
+        assert!(lines.iter().all(|&(level, _)| level != InfoLogLevel::Debug && level != InfoLogLevel::Info));
+    }
 }
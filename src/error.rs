@@ -8,6 +8,7 @@
 //! non-const method, all threads accessing the same Status must use
 //! external synchronization.
 
+use std::error;
 use std::fmt;
 use std::mem;
 use std::ffi::CStr;
@@ -54,6 +55,9 @@ pub struct Status {
     raw: *mut ll::rocks_status_t,
 }
 
+unsafe impl Send for Status {}
+unsafe impl Sync for Status {}
+
 impl ToRaw<ll::rocks_status_t> for Status {
     fn raw(&self) -> *mut ll::rocks_status_t {
         self.raw
@@ -86,10 +90,60 @@ impl Status {
         }
     }
 
+    /// Create an error Status with the given code, used by Rust implemented
+    /// callbacks such as `FileSystem`.
+    pub fn with_code(code: Code, msg: &str) -> Status {
+        assert!(code != Code::_Ok, "Can't create a Ok status in Rust");
+        unsafe {
+            let ccode = mem::transmute(code);
+            Status { raw: ll::rocks_status_create_with_code_and_msg(ccode, msg.as_ptr() as *const _, msg.len()) }
+        }
+    }
+
+    /// Returns true iff the status indicates a NotFound error.
     pub fn is_not_found(&self) -> bool {
         self.code() == Code::NotFound
     }
 
+    /// Returns true iff the status indicates a Corruption error.
+    pub fn is_corruption(&self) -> bool {
+        self.code() == Code::Corruption
+    }
+
+    /// Returns true iff the status indicates a NotSupported error.
+    pub fn is_not_supported(&self) -> bool {
+        self.code() == Code::NotSupported
+    }
+
+    /// Returns true iff the status indicates an InvalidArgument error.
+    pub fn is_invalid_argument(&self) -> bool {
+        self.code() == Code::InvalidArgument
+    }
+
+    /// Returns true iff the status indicates an IOError.
+    pub fn is_io_error(&self) -> bool {
+        self.code() == Code::IOError
+    }
+
+    /// Returns true iff the status indicates that a resource is Busy and
+    /// temporarily could not be acquired.
+    pub fn is_busy(&self) -> bool {
+        self.code() == Code::Busy
+    }
+
+    /// Returns true iff the status indicates a TimedOut error.
+    pub fn is_timed_out(&self) -> bool {
+        self.code() == Code::TimedOut
+    }
+
+    /// Returns true iff the status indicates a NoSpace error.
+    ///
+    /// This is caused by an I/O error returning the specific "out of space"
+    /// error condition.
+    pub fn is_no_space(&self) -> bool {
+        self.code() == Code::IOError && self.subcode() == SubCode::NoSpace
+    }
+
     pub fn code(&self) -> Code {
         unsafe { mem::transmute(ll::rocks_status_code(self.raw)) }
     }
@@ -128,3 +182,70 @@ impl fmt::Debug for Status {
         write!(f, "{:?}({:?}, {:?})", self.code(), self.subcode(), self.state())
     }
 }
+
+impl error::Error for Status {
+    fn description(&self) -> &str {
+        self.state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+    use super::super::sst_file_writer::SstFileWriter;
+
+    #[test]
+    fn status_code() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        let not_found = db.get(&ReadOptions::default(), b"non-exist").unwrap_err();
+        assert_eq!(not_found.code(), Code::NotFound);
+        assert_eq!(not_found.subcode(), SubCode::None);
+
+        let sst_dir = ::tempdir::TempDir::new_in(".", "sst").unwrap();
+        let writer = SstFileWriter::builder().build();
+        writer.open(sst_dir.path().join("2333.sst")).unwrap();
+        assert!(writer.put(b"key-2", b"").is_ok());
+        let out_of_order = writer.put(b"key-1", b"").unwrap_err();
+        assert_eq!(out_of_order.code(), Code::InvalidArgument);
+        assert!(out_of_order.state().contains("order"), "got {:?}", out_of_order);
+
+        assert!(not_found.code() != out_of_order.code());
+    }
+
+    #[test]
+    fn status_predicates() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        let err = db.get(&ReadOptions::default(), b"non-exist").unwrap_err();
+        assert!(err.is_not_found());
+        assert!(!err.is_corruption());
+        assert!(!err.is_busy());
+        assert!(!err.is_timed_out());
+        assert!(!err.is_io_error());
+
+        let err = Status::with_code(Code::Busy, "busy");
+        assert!(err.is_busy());
+        assert!(!err.is_not_found());
+        let err = Status::with_code(Code::IOError, "io");
+        assert!(err.is_io_error());
+        assert!(!err.is_no_space());
+    }
+
+    #[test]
+    fn status_as_std_error() {
+        use std::error::Error;
+
+        fn read_missing(db: &DB) -> ::std::result::Result<usize, Box<Error + Send + Sync>> {
+            let val = try!(db.get(&ReadOptions::default(), b"non-exist"));
+            Ok(val.len())
+        }
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        let err = read_missing(&db).unwrap_err();
+        assert!(err.to_string().contains("NotFound"), "got {}", err);
+        assert!(err.downcast_ref::<Status>().unwrap().is_not_found());
+    }
+}
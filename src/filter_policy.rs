@@ -104,3 +104,47 @@ impl FilterPolicy {
 // fn key_may_match(&self, key: &[u8], filter: &[u8]) -> bool;
 // }
 //
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn bloom_filter_key_may_exist() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.table_factory_block_based(
+                        BlockBasedTableOptions::default()
+                            .filter_policy(Some(FilterPolicy::new_bloom_filter(10, false))),
+                    )
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..1000 {
+            let key = format!("key-{:04}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+        }
+        // memtable has no bloom filter by default, answer from sst files only
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        for i in 0..1000 {
+            let key = format!("key-{:04}", i);
+            assert!(db.key_may_exist(&ReadOptions::default(), key.as_bytes()));
+        }
+
+        let false_positives = (0..1000)
+            .filter(|i| {
+                let key = format!("absent-{:04}", i);
+                db.key_may_exist(&ReadOptions::default(), key.as_bytes())
+            })
+            .count();
+        // ~1% with 10 bits per key
+        assert!(false_positives < 50, "false positives: {}", false_positives);
+    }
+}
@@ -104,4 +104,31 @@ mod tests {
 
         // FIXME: why thread_pool changes?
     }
+
+    #[test]
+    fn iostats_context_cold_read() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+            assert!(db.put(&Default::default(), b"key", vec![b'A'; 64 * 1024].as_ref()).is_ok());
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+
+        // reopened, the block cache is empty
+        let db = DB::open(Options::default(), &tmp_dir).unwrap();
+
+        set_perf_level(PerfLevel::EnableTime);
+        let stat = IOStatsContext::current();
+        stat.reset();
+        assert_eq!(stat.bytes_read, 0);
+
+        assert_eq!(db.get(&Default::default(), b"key").unwrap().len(), 64 * 1024);
+        assert!(stat.bytes_read > 0);
+        assert_eq!(stat.bytes_written, 0);
+
+        // served from the block cache
+        stat.reset();
+        assert!(db.get(&Default::default(), b"key").is_ok());
+        assert_eq!(stat.bytes_read, 0);
+    }
 }
@@ -10,6 +10,7 @@ use std::os::raw::c_void;
 use rocks_sys as ll;
 
 use error::Status;
+use slice::CVec;
 use to_raw::FromRaw;
 
 use super::Result;
@@ -279,6 +280,135 @@ impl<'a> iter::Iterator for IntoRevIter<'a> {
     }
 }
 
+/// A rust-style Iterator over owned copies of key/value pairs, made by `DB::iter()`
+///
+/// Iteration stops at the end of the source, at `iterate_upper_bound`, or on error.
+/// Check `status()` after iteration to tell an error from the end.
+///
+/// Also a `DoubleEndedIterator`, consuming from both ends never yields a key twice.
+pub struct DbIterator<'a> {
+    inner: Iterator<'a>,
+    direction: Direction,
+    /// last key yielded by `next()`
+    front: Option<Vec<u8>>,
+    /// last key yielded by `next_back()`
+    back: Option<Vec<u8>>,
+    finished: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    Forward,
+    Reverse,
+}
+
+impl<'a> DbIterator<'a> {
+    /// Positions the iterator at the first key in the source.
+    pub fn new(mut inner: Iterator<'a>) -> DbIterator<'a> {
+        inner.seek_to_first();
+        DbIterator {
+            inner: inner,
+            direction: Direction::Forward,
+            front: None,
+            back: None,
+            finished: false,
+        }
+    }
+
+    /// If an error has occurred during iteration, return it.
+    pub fn status(&self) -> Result<()> {
+        self.inner.status()
+    }
+
+    pub fn into_inner(self) -> Iterator<'a> {
+        self.inner
+    }
+
+    /// Copies out current key/value, or marks the iterator finished when it
+    /// is exhausted or reaches the key last yielded from the other end.
+    fn take_current(&mut self) -> Option<(CVec<u8>, CVec<u8>)> {
+        if self.finished || !self.inner.is_valid() {
+            self.finished = true;
+            return None;
+        }
+        let other_end = match self.direction {
+            Direction::Forward => &self.back,
+            Direction::Reverse => &self.front,
+        };
+        if other_end.as_ref().map_or(false, |k| k.as_slice() == self.inner.key()) {
+            self.finished = true;
+            return None;
+        }
+        let kv = unsafe {
+            let mut klen = 0;
+            let kptr = ll::rocks_iter_copy_key(self.inner.raw, &mut klen);
+            let mut vlen = 0;
+            let vptr = ll::rocks_iter_copy_value(self.inner.raw, &mut vlen);
+            (CVec::from_raw_parts(kptr as *mut u8, klen), CVec::from_raw_parts(vptr as *mut u8, vlen))
+        };
+        match self.direction {
+            Direction::Forward => {
+                remember_key(&mut self.front, &kv.0);
+                self.inner.next();
+            }
+            Direction::Reverse => {
+                remember_key(&mut self.back, &kv.0);
+                self.inner.prev();
+            }
+        }
+        Some(kv)
+    }
+}
+
+/// Stores `key` as the last yielded key of one end, reusing its buffer.
+fn remember_key(slot: &mut Option<Vec<u8>>, key: &[u8]) {
+    match *slot {
+        Some(ref mut buf) => {
+            buf.clear();
+            buf.extend_from_slice(key);
+        }
+        None => *slot = Some(key.to_vec()),
+    }
+}
+
+impl<'a> iter::Iterator for DbIterator<'a> {
+    type Item = (CVec<u8>, CVec<u8>);
+
+    fn next(&mut self) -> Option<(CVec<u8>, CVec<u8>)> {
+        if !self.finished && self.direction == Direction::Reverse {
+            self.direction = Direction::Forward;
+            match self.front {
+                Some(ref key) => {
+                    self.inner.seek(key);
+                    if self.inner.is_valid() && self.inner.key() == key.as_slice() {
+                        self.inner.next();
+                    }
+                }
+                None => self.inner.seek_to_first(),
+            }
+        }
+        self.take_current()
+    }
+}
+
+impl<'a> iter::DoubleEndedIterator for DbIterator<'a> {
+    fn next_back(&mut self) -> Option<(CVec<u8>, CVec<u8>)> {
+        if !self.finished && self.direction == Direction::Forward {
+            self.direction = Direction::Reverse;
+            match self.back {
+                Some(ref key) => {
+                    self.inner.seek_for_prev(key);
+                    if self.inner.is_valid() && self.inner.key() == key.as_slice() {
+                        self.inner.prev();
+                    }
+                }
+                None => self.inner.seek_to_last(),
+            }
+        }
+        self.take_current()
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -389,4 +519,84 @@ mod tests {
             .collect();
         assert_eq!(keys, vec!["k9", "k8", "k6", "k5", "k4", "k3", "k2", "k1"]);
     }
+
+    #[test]
+    fn db_iterator() {
+        use tempdir::TempDir;
+        let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let db = DB::open(opt, tmp_dir.path()).unwrap();
+
+        assert_eq!(db.iter(&ReadOptions::default()).count(), 0);
+
+        let mut expected = vec![];
+        for i in 0..100 {
+            let key = format!("k{:03}", i);
+            let val = format!("v{}", i * i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), val.as_bytes()).is_ok());
+            if i == 50 {
+                assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            }
+            expected.push((key.into_bytes(), val.into_bytes()));
+        }
+
+        let ropts = ReadOptions::default();
+        let mut it = db.iter(&ropts);
+        let kvs = it.by_ref()
+            .map(|(k, v)| (k.to_vec(), v.to_vec()))
+            .collect::<Vec<_>>();
+        assert!(it.status().is_ok());
+        assert_eq!(kvs, expected);
+        // stays at the end
+        assert!(it.next().is_none());
+
+        let bound = b"k010";
+        let keys = db.iter(&ReadOptions::default().iterate_upper_bound(bound))
+            .map(|(k, _)| String::from_utf8_lossy(&k).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(keys.len(), 10);
+        assert_eq!(keys.last().unwrap(), "k009");
+    }
+
+    #[test]
+    fn db_iterator_rev() {
+        use tempdir::TempDir;
+        let tmp_dir = TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let db = DB::open(opt, tmp_dir.path()).unwrap();
+
+        let ropts = ReadOptions::default();
+        assert!(db.iter(&ropts).next_back().is_none());
+
+        for i in 0..20 {
+            let key = format!("k{:02}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"val").is_ok());
+        }
+
+        let forward = db.iter(&ropts).map(|(k, _)| k.to_vec()).collect::<Vec<_>>();
+        let mut reverse = db.iter(&ropts).rev().map(|(k, _)| k.to_vec()).collect::<Vec<_>>();
+        assert_eq!(forward.len(), 20);
+        reverse.reverse();
+        assert_eq!(forward, reverse);
+
+        // consume from both ends, each key exactly once
+        let mut it = db.iter(&ropts);
+        let mut keys = vec![];
+        let mut tail = vec![];
+        loop {
+            match it.next() {
+                Some((k, _)) => keys.push(k.to_vec()),
+                None => break,
+            }
+            for _ in 0..2 {
+                if let Some((k, _)) = it.next_back() {
+                    tail.push(k.to_vec());
+                }
+            }
+        }
+        assert!(it.next_back().is_none());
+        tail.reverse();
+        keys.extend(tail);
+        assert_eq!(keys, forward);
+    }
 }
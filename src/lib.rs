@@ -38,7 +38,9 @@ pub use error::Status;
 pub type Result<T> = result::Result<T, Status>;
 
 pub mod advanced_options;
+pub mod backup;
 pub mod cache;
+pub mod checkpoint;
 pub mod persistent_cache;
 pub mod compaction_filter;
 pub mod comparator;
@@ -48,6 +50,7 @@ pub mod iterator;
 pub mod listener;
 pub mod merge_operator;
 pub mod options;
+pub mod options_util;
 pub mod rate_limiter;
 pub mod slice;
 pub mod slice_transform;
@@ -61,6 +64,7 @@ pub mod table_properties;
 pub mod types;
 pub mod universal_compaction;
 pub mod write_batch;
+pub mod write_batch_with_index;
 pub mod write_buffer_manager;
 pub mod metadata;
 pub mod db_dump_tool;
@@ -70,7 +74,10 @@ pub mod perf_context;
 pub mod wal_filter;
 pub mod filter_policy;
 pub mod convenience;
+pub mod transaction;
 pub mod transaction_log;
+pub mod optimistic_transaction_db;
+pub mod transaction_db;
 pub mod compaction_job_stats;
 pub mod flush_block_policy;
 pub mod debug;
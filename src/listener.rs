@@ -1034,4 +1034,73 @@ mod tests {
         // safe shutdown
         assert!(db.pause_background_work().is_ok());
     }
+
+    #[test]
+    fn event_listener_counts_flushes() {
+        use std::sync::{Arc, Mutex};
+
+        // events are only recorded here, asserting inside a callback would unwind into C++
+        struct FlushRecorder {
+            // (cf name, file path, data size)
+            flushes: Arc<Mutex<Vec<(String, String, u64)>>>,
+            // (cf name, file path, file size)
+            created_files: Arc<Mutex<Vec<(String, String, u64)>>>,
+        }
+
+        impl EventListener for FlushRecorder {
+            fn on_flush_completed(&mut self, db: &DBRef, flush_job_info: &FlushJobInfo) {
+                self.flushes.lock().unwrap().push((
+                    flush_job_info.cf_name.to_owned(),
+                    flush_job_info.file_path.to_owned(),
+                    flush_job_info.table_properties.data_size(),
+                ));
+            }
+
+            fn on_table_file_created(&mut self, info: &TableFileCreationInfo) {
+                self.created_files.lock().unwrap().push((
+                    info.cf_name().to_owned(),
+                    info.file_path().to_owned(),
+                    info.file_size(),
+                ));
+            }
+        }
+
+        let flushes = Arc::new(Mutex::new(Vec::new()));
+        let created_files = Arc::new(Mutex::new(Vec::new()));
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default().map_db_options(|db| {
+                db.create_if_missing(true).add_listener(FlushRecorder {
+                    flushes: flushes.clone(),
+                    created_files: created_files.clone(),
+                })
+            }),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..3 {
+            let key = format!("key-{}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+
+        // closing the DB waits for background jobs, listeners included
+        drop(db);
+
+        let flushes = flushes.lock().unwrap();
+        assert_eq!(flushes.len(), 3);
+        for &(ref cf_name, ref file_path, data_size) in flushes.iter() {
+            assert_eq!(cf_name, "default");
+            assert!(file_path.ends_with(".sst"));
+            assert!(data_size > 0);
+        }
+        let created_files = created_files.lock().unwrap();
+        assert_eq!(created_files.len(), 3);
+        for &(ref cf_name, ref file_path, file_size) in created_files.iter() {
+            assert_eq!(cf_name, "default");
+            assert!(file_path.ends_with(".sst"));
+            assert!(file_size > 0);
+        }
+    }
 }
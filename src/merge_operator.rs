@@ -136,6 +136,29 @@ pub trait MergeOperator {
         false
     }
 
+    /// This function performs merge when all the operands are themselves merge
+    /// operation types that you would have passed to a `DB::merge()` call in the
+    /// same order (front first).
+    /// (i.e. `DB::merge(key, operands[0])`, followed by
+    ///  `DB::merge(key, operands[1])`, ...)
+    ///
+    /// `partial_merge_multi` should combine them into a single merge operation.
+    /// The returned value should be constructed such that a call to
+    /// `DB::merge(key, new_value)` would yield the same result as a call
+    /// to `DB::merge(key, operands[0])` followed by `DB::merge(key, operands[1])`,
+    /// ...
+    ///
+    /// Returns `None` if it is impossible or infeasible to combine the operands,
+    /// in which case the operands are left as they are. RocksDB will call
+    /// `full_merge` with the whole operand list later.
+    ///
+    /// All operands will be presented in the order they were passed to the DB,
+    /// at least two of them. Called when flushing or compacting merge operands
+    /// of a key with no base value.
+    fn partial_merge_multi(&self, key: &[u8], operands: &[&[u8]], logger: &Logger) -> Option<Vec<u8>> {
+        None
+    }
+
     /// The name of the MergeOperator. Used to check for MergeOperator
     /// mismatches (i.e., a DB created with one MergeOperator is
     /// accessed using a different MergeOperator)
@@ -208,6 +231,35 @@ pub mod c {
         }
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn rust_merge_operator_call_partial_merge_multi(
+        op: *mut (),
+        key: &&[u8],
+        operand_ptrs: *const *const u8,
+        operand_lens: *const usize,
+        num_operands: usize,
+        new_value: *mut *const u8,
+        new_value_len: *mut usize,
+        logger: &Logger,
+    ) -> i32 {
+        assert!(!op.is_null());
+        let operator = op as *mut Box<MergeOperator>;
+        let operands = (0..num_operands as isize)
+            .map(|i| slice::from_raw_parts(*operand_ptrs.offset(i), *operand_lens.offset(i)))
+            .collect::<Vec<&[u8]>>();
+        if let Some(val) = (*operator).partial_merge_multi(*key, &operands, logger) {
+            // shrink capacity to len, as `rust_drop_vec_u8` expects
+            let val = val.into_boxed_slice();
+            *new_value_len = val.len();
+            *new_value = val.as_ptr();
+            // NOTE: this val is dropped in C by `rust_drop_vec_u8`
+            mem::forget(val);
+            true as _
+        } else {
+            false as _
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn rust_merge_operator_drop(op: *mut ()) {
         assert!(!op.is_null());
@@ -233,6 +285,7 @@ pub mod c {
             let operator = op as *mut Box<AssociativeMergeOperator>;
             let nval = (*operator).merge(*key, existing_value.map(|&s| s), *value, logger);
             if let Some(val) = nval {
+                let val = val.into_boxed_slice();
                 *new_value_len = val.len();
                 *new_value = val.as_ptr();
                 // NOTE: this val is dropped in C by `rust_drop_vec_u8`
@@ -342,6 +395,60 @@ mod tests {
         assert_eq!(String::from_utf8_lossy(ret.unwrap().as_ref()), "HEAD|value|value2|value3|value4|value");
     }
 
+    #[test]
+    fn assoc_merge_concat_with_comma() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+
+        struct CommaConcat;
+
+        impl AssociativeMergeOperator for CommaConcat {
+            fn merge(
+                &self,
+                key: &[u8],
+                existing_value: Option<&[u8]>,
+                value: &[u8],
+                logger: &Logger,
+            ) -> Option<Vec<u8>> {
+                let mut ret = existing_value.map(|v| v.to_vec()).unwrap_or_default();
+                if !ret.is_empty() {
+                    ret.push(b',');
+                }
+                ret.extend_from_slice(value);
+                Some(ret)
+            }
+        }
+
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.associative_merge_operator(Box::new(CommaConcat))),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.merge(&WriteOptions::default(), b"tags", b"a").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"tags", b"b").is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"tags").unwrap(), b"a,b".as_ref());
+
+        // operands on both sides of a flush
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"tags", b"c").is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"tags").unwrap(), b"a,b,c".as_ref());
+
+        // on top of an existing value
+        assert!(db.put(&WriteOptions::default(), b"colors", b"red").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"colors", b"green").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"colors", b"blue").is_ok());
+
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"tags").unwrap(), b"a,b,c".as_ref());
+        assert_eq!(db.get(&ReadOptions::default(), b"colors").unwrap(), b"red,green,blue".as_ref());
+
+        // a delete resets the merge chain
+        assert!(db.delete(&WriteOptions::default(), b"colors").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"colors", b"black").is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"colors").unwrap(), b"black".as_ref());
+    }
+
     #[test]
     fn merge_assign_concat_operands() {
         use tempdir::TempDir;
@@ -439,4 +546,102 @@ mod tests {
         // println!("ret => {:?}", ret.as_ref().map(|s| String::from_utf8_lossy(s)));
         assert_eq!(ret.unwrap().as_ref(), b"I-am-the-test-233");
     }
+
+    #[test]
+    fn merge_json_patch_partial_merge_multi() {
+        use std::collections::BTreeMap;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use merge_operator::{MergeOperationInput, MergeOperationOutput};
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+
+        // "k=v,k=v" objects, a patch with an empty value removes the field
+        fn parse(raw: &[u8]) -> BTreeMap<String, String> {
+            String::from_utf8_lossy(raw)
+                .split(',')
+                .filter(|kv| !kv.is_empty())
+                .map(|kv| {
+                    let mut it = kv.splitn(2, '=');
+                    (it.next().unwrap().to_owned(), it.next().unwrap_or("").to_owned())
+                })
+                .collect()
+        }
+
+        fn dump(obj: &BTreeMap<String, String>) -> Vec<u8> {
+            obj.iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(",")
+                .into_bytes()
+        }
+
+        struct JsonPatch {
+            partial_merges: Arc<AtomicUsize>,
+            full_merge_operands: Arc<AtomicUsize>,
+        }
+
+        impl MergeOperator for JsonPatch {
+            fn full_merge(&self, merge_in: &MergeOperationInput, merge_out: &mut MergeOperationOutput) -> bool {
+                let mut obj = merge_in.existing_value.map(|v| parse(v)).unwrap_or_default();
+                if merge_in.existing_value.is_some() {
+                    self.full_merge_operands.store(merge_in.operands().len(), Ordering::SeqCst);
+                }
+                for patch in merge_in.operands() {
+                    for (k, v) in parse(patch) {
+                        if v.is_empty() {
+                            obj.remove(&k);
+                        } else {
+                            obj.insert(k, v);
+                        }
+                    }
+                }
+                merge_out.assign(&dump(&obj));
+                true
+            }
+
+            fn partial_merge_multi(&self, key: &[u8], operands: &[&[u8]], logger: &Logger) -> Option<Vec<u8>> {
+                assert!(operands.len() >= 2);
+                self.partial_merges.fetch_add(1, Ordering::SeqCst);
+                // removals are kept in the combined patch
+                let mut patch = BTreeMap::new();
+                for op in operands {
+                    patch.extend(parse(op));
+                }
+                Some(dump(&patch))
+            }
+        }
+
+        let partial_merges = Arc::new(AtomicUsize::new(0));
+        let full_merge_operands = Arc::new(AtomicUsize::new(0));
+        let op = JsonPatch {
+            partial_merges: partial_merges.clone(),
+            full_merge_operands: full_merge_operands.clone(),
+        };
+
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.merge_operator(Box::new(op))),
+            &tmp_dir,
+        ).unwrap();
+
+        // existing value and several operands in one full_merge call
+        assert!(db.put(&WriteOptions::default(), b"user", b"age=1,name=rocks").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"user", b"age=2").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"user", b"city=sh,name=").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"user", b"lang=rust").is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"user").unwrap(), b"age=2,city=sh,lang=rust".as_ref());
+        assert_eq!(full_merge_operands.load(Ordering::SeqCst), 3);
+
+        // without a base value, flush combines the patches with partial_merge_multi
+        assert!(db.merge(&WriteOptions::default(), b"patch", b"a=1,b=2").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"patch", b"b=").is_ok());
+        assert!(db.merge(&WriteOptions::default(), b"patch", b"c=3").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(partial_merges.load(Ordering::SeqCst) > 0);
+
+        assert_eq!(db.get(&ReadOptions::default(), b"patch").unwrap(), b"a=1,c=3".as_ref());
+        assert_eq!(db.get(&ReadOptions::default(), b"user").unwrap(), b"age=2,city=sh,lang=rust".as_ref());
+    }
 }
@@ -0,0 +1,124 @@
+//! A DB with optimistic concurrency control: transactions don't take locks,
+//! conflicts are checked when they commit.
+
+use std::ops;
+use std::path::Path;
+use std::ptr;
+
+use rocks_sys as ll;
+
+use db::DBRef;
+use error::Status;
+use options::{Options, WriteOptions};
+use to_raw::{FromRaw, ToRaw};
+use transaction::Transaction;
+use Result;
+
+/// A DB supporting optimistic transactions.
+///
+/// Derefs to the underlying base `DBRef`, which can be used for plain,
+/// non-transactional reads and writes.
+pub struct OptimisticTransactionDB {
+    raw: *mut ll::rocks_optimistic_transaction_db_t,
+    base: Box<DBRef<'static>>,
+}
+
+unsafe impl Sync for OptimisticTransactionDB {}
+unsafe impl Send for OptimisticTransactionDB {}
+
+impl Drop for OptimisticTransactionDB {
+    fn drop(&mut self) {
+        unsafe {
+            // base DB is owned by the OptimisticTransactionDB, only free the wrapper
+            ll::rocks_optimistic_transaction_db_close_base_db(self.base.raw());
+            ll::rocks_optimistic_transaction_db_close(self.raw);
+        }
+    }
+}
+
+impl ops::Deref for OptimisticTransactionDB {
+    type Target = DBRef<'static>;
+
+    fn deref(&self) -> &DBRef<'static> {
+        &self.base
+    }
+}
+
+impl OptimisticTransactionDB {
+    /// Open an OptimisticTransactionDB similar to `DB::open()`.
+    pub fn open<T: AsRef<Options>, P: AsRef<Path>>(options: T, name: P) -> Result<OptimisticTransactionDB> {
+        let name = name.as_ref().to_str().expect("valid path");
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_optimistic_transaction_db_open(
+                options.as_ref().raw(),
+                name.as_bytes().as_ptr() as *const _,
+                name.len(),
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| {
+                OptimisticTransactionDB {
+                    raw: raw,
+                    base: Box::new(DBRef::from_ll(ll::rocks_optimistic_transaction_db_get_base_db(raw))),
+                }
+            })
+        }
+    }
+
+    /// Starts a new Transaction.
+    ///
+    /// Conflicts with writes made outside of the transaction, or by other
+    /// transactions, after the keys were first read or written are detected
+    /// on `commit()`.
+    pub fn begin_transaction(&self, write_options: &WriteOptions) -> Transaction {
+        unsafe {
+            Transaction::from_ll(ll::rocks_optimistic_transaction_db_begin_transaction(
+                self.raw,
+                write_options.raw(),
+            ))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+    use error::Code;
+
+    #[test]
+    fn optimistic_transaction_conflict() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = OptimisticTransactionDB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"counter", b"0").is_ok());
+
+        let txn1 = db.begin_transaction(&WriteOptions::default());
+        let txn2 = db.begin_transaction(&WriteOptions::default());
+
+        assert_eq!(txn1.get_for_update(&ReadOptions::default(), b"counter").unwrap(), b"0");
+        assert_eq!(txn2.get_for_update(&ReadOptions::default(), b"counter").unwrap(), b"0");
+
+        assert!(txn1.put(b"counter", b"1").is_ok());
+        assert!(txn2.put(b"counter", b"2").is_ok());
+        // own uncommitted writes are visible inside the transaction only
+        assert_eq!(txn1.get(&ReadOptions::default(), b"counter").unwrap(), b"1");
+        assert_eq!(db.get(&ReadOptions::default(), b"counter").unwrap(), b"0".as_ref());
+
+        assert!(txn1.commit().is_ok());
+        let err = txn2.commit().unwrap_err();
+        assert_eq!(err.code(), Code::Busy);
+
+        assert_eq!(db.get(&ReadOptions::default(), b"counter").unwrap(), b"1".as_ref());
+
+        let txn3 = db.begin_transaction(&WriteOptions::default());
+        assert!(txn3.delete(b"counter").is_ok());
+        assert!(txn3.get(&ReadOptions::default(), b"counter").unwrap_err().is_not_found());
+        assert!(txn3.rollback().is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"counter").unwrap(), b"1".as_ref());
+    }
+}
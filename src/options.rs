@@ -27,10 +27,13 @@ use table::{BlockBasedTableOptions, CuckooTableOptions, PlainTableOptions};
 use comparator::Comparator;
 use slice_transform::SliceTransform;
 use snapshot::Snapshot;
+use error::Status;
 use table_properties::TablePropertiesCollectorFactory;
 
 use to_raw::{FromRaw, ToRaw};
 
+use super::Result;
+
 lazy_static! {
     // since all Options field are guaranteed to be thread safe
     static ref DEFAULT_OPTIONS: Options = {
@@ -157,6 +160,13 @@ impl Default for ColumnFamilyOptions {
     }
 }
 
+impl Clone for ColumnFamilyOptions {
+    /// Copies all fields, shared_ptr members (caches, factories, ...) are shared
+    fn clone(&self) -> Self {
+        ColumnFamilyOptions { raw: unsafe { ll::rocks_cfoptions_copy(self.raw) } }
+    }
+}
+
 impl Drop for ColumnFamilyOptions {
     fn drop(&mut self) {
         unsafe {
@@ -183,20 +193,39 @@ impl fmt::Display for ColumnFamilyOptions {
     }
 }
 
+impl FromRaw<ll::rocks_cfoptions_t> for ColumnFamilyOptions {
+    unsafe fn from_ll(raw: *mut ll::rocks_cfoptions_t) -> ColumnFamilyOptions {
+        ColumnFamilyOptions { raw: raw }
+    }
+}
+
 impl ColumnFamilyOptions {
     /// Create ColumnFamilyOptions with default values for all fields
     pub fn new() -> ColumnFamilyOptions {
         ColumnFamilyOptions { raw: unsafe { ll::rocks_cfoptions_create() } }
     }
 
-    unsafe fn from_ll(raw: *mut ll::rocks_cfoptions_t) -> ColumnFamilyOptions {
-        ColumnFamilyOptions { raw: raw }
-    }
-
     pub fn from_options(opt: &Options) -> ColumnFamilyOptions {
         ColumnFamilyOptions { raw: unsafe { ll::rocks_cfoptions_create_from_options(opt.raw()) } }
     }
 
+    /// Construct ColumnFamilyOptions by applying a "key1=value1;key2=value2" style
+    /// string on top of `base`, e.g. `"write_buffer_size=64M;max_write_buffer_number=4"`.
+    ///
+    /// Unknown option names or malformed values result in `Status::InvalidArgument`.
+    pub fn from_string(base: &ColumnFamilyOptions, opts_str: &str) -> Result<ColumnFamilyOptions> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_get_cfoptions_from_string(
+                base.raw,
+                opts_str.as_ptr() as *const _,
+                opts_str.len(),
+                &mut status,
+            );
+            Status::from_ll(status).map(|()| ColumnFamilyOptions { raw: raw })
+        }
+    }
+
     /// Some functions that make it easier to optimize RocksDB
 
     /// Use this if your DB is very small (like under 1GB) and you don't want to
@@ -342,11 +371,11 @@ impl ColumnFamilyOptions {
     ///
     /// Default: nullptr
     pub fn compaction_filter_factory(self, factory: Box<CompactionFilterFactory>) -> Self {
-        // unsafe {
-        // ll::rocks_cfoptions_set_compaction_filter_factory(self.raw, )
-        // }
-        // self
-        unimplemented!()
+        unsafe {
+            let raw_ptr = Box::into_raw(Box::new(factory)); // Box<Box<CompactionFilterFactory>>
+            ll::rocks_cfoptions_set_compaction_filter_factory_by_trait(self.raw, raw_ptr as *mut _);
+        }
+        self
     }
 
     // -------------------
@@ -846,6 +875,9 @@ impl ColumnFamilyOptions {
     /// according to compression_per_level[1], L3 using compression_per_level[2]
     /// and L4 using compression_per_level[3]. Compaction for each level can
     /// change when data grows.
+    ///
+    /// For Rust: the slice may be shorter than `num_levels`, levels past its end
+    /// use its last entry. Extra entries past `num_levels` are ignored.
     pub fn compression_per_level(self, val: &[CompressionType]) -> Self {
         unsafe {
             ll::rocks_cfoptions_set_compression_per_level(
@@ -1371,6 +1403,7 @@ impl ColumnFamilyOptions {
 ///
 /// Default: NORMAL
 #[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum AccessHint {
     None,
     Normal,
@@ -1389,6 +1422,13 @@ impl Default for DBOptions {
     }
 }
 
+impl Clone for DBOptions {
+    /// Copies all fields, shared_ptr members (env, rate limiter, ...) are shared
+    fn clone(&self) -> Self {
+        DBOptions { raw: unsafe { ll::rocks_dboptions_copy(self.raw) } }
+    }
+}
+
 impl Drop for DBOptions {
     fn drop(&mut self) {
         unsafe {
@@ -1421,10 +1461,29 @@ impl fmt::Display for DBOptions {
     }
 }
 
-impl DBOptions {
+impl FromRaw<ll::rocks_dboptions_t> for DBOptions {
     unsafe fn from_ll(raw: *mut ll::rocks_dboptions_t) -> DBOptions {
         DBOptions { raw: raw }
     }
+}
+
+impl DBOptions {
+    /// Construct DBOptions by applying a "key1=value1;key2=value2" style
+    /// string on top of `base`, e.g. `"max_background_jobs=4;max_open_files=100"`.
+    ///
+    /// Unknown option names or malformed values result in `Status::InvalidArgument`.
+    pub fn from_string(base: &DBOptions, opts_str: &str) -> Result<DBOptions> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_get_dboptions_from_string(
+                base.raw,
+                opts_str.as_ptr() as *const _,
+                opts_str.len(),
+                &mut status,
+            );
+            Status::from_ll(status).map(|()| DBOptions { raw: raw })
+        }
+    }
 
     /// If true, the database will be created if it is missing.
     ///
@@ -1516,11 +1575,14 @@ impl DBOptions {
     ///
     /// Default: nullptr
     pub fn sst_file_manager(self, val: Option<SstFileManager>) -> Self {
-        // unsafe {
-        //     ll::rocks_dboptions_set_sst_file_manager(self.raw, val);
-        // }
-        // self
-        unimplemented!()
+        unsafe {
+            if let Some(manager) = val {
+                ll::rocks_dboptions_set_sst_file_manager(self.raw, manager.raw());
+            } else {
+                ll::rocks_dboptions_set_sst_file_manager(self.raw, ptr::null_mut());
+            }
+        }
+        self
     }
 
     /// Any internal progress/error information generated by the db will
@@ -1539,6 +1601,8 @@ impl DBOptions {
         self
     }
 
+    /// Messages below this level are dropped. Also applied to `info_log`
+    /// when one is set.
     pub fn info_log_level(self, val: InfoLogLevel) -> Self {
         unsafe {
             ll::rocks_dboptions_set_info_log_level(self.raw, mem::transmute(val));
@@ -2369,6 +2433,13 @@ impl Default for Options {
     }
 }
 
+impl Clone for Options {
+    /// Copies all fields, shared_ptr members (caches, env, ...) are shared
+    fn clone(&self) -> Self {
+        Options { raw: unsafe { ll::rocks_options_copy(self.raw) } }
+    }
+}
+
 impl Drop for Options {
     fn drop(&mut self) {
         unsafe {
@@ -2618,6 +2689,10 @@ impl<'a> ReadOptions<'a> {
     /// added data) and is optimized for sequential reads. It will return records
     /// that were inserted into the database after the creation of the iterator.
     ///
+    /// Rust: new records only show up after a `seek*()` call, an exhausted
+    /// iterator stays invalid until it is re-seeked. A tailing iterator does not
+    /// support `snapshot` and `prev()`, it reads the latest state at each seek.
+    ///
     /// Default: false
     pub fn tailing(self, val: bool) -> Self {
         unsafe {
@@ -2771,6 +2846,12 @@ impl WriteOptions {
 
     /// If true, writes will not first go to the write ahead log,
     /// and the write may got lost after a crash.
+    ///
+    /// Such writes are only durable once their memtable is flushed, use
+    /// `DB::flush` after a bulk load. Memtables are also flushed on close, unless
+    /// `DBOptions::avoid_flush_during_shutdown` is set.
+    ///
+    /// Default: false
     pub fn disable_wal(self, val: bool) -> Self {
         unsafe {
             ll::rocks_writeoptions_set_disable_wal(self.raw, val as u8);
@@ -3092,6 +3173,170 @@ mod tests {
         assert!(format!("{}", opts).contains("max_write_buffer_number=5"));
     }
 
+    #[test]
+    fn options_from_string() {
+        let base = ColumnFamilyOptions::default().max_write_buffer_number(5);
+        let opts = ColumnFamilyOptions::from_string(&base, "write_buffer_size=64M;min_write_buffer_number_to_merge=2")
+            .unwrap();
+        let s = opts.to_string();
+        assert!(s.contains("write_buffer_size=67108864;"), "got {}", s);
+        assert!(s.contains("min_write_buffer_number_to_merge=2;"));
+        // unmentioned fields are kept from base
+        assert!(s.contains("max_write_buffer_number=5;"));
+
+        match ColumnFamilyOptions::from_string(&base, "no_such_option=1") {
+            Err(e) => assert!(e.is_invalid_argument(), "got {:?}", e),
+            Ok(_) => panic!("unknown option must be rejected"),
+        }
+
+        let opts = DBOptions::from_string(&DBOptions::default(), "max_background_jobs=4;max_open_files=100").unwrap();
+        let s = opts.to_string();
+        assert!(s.contains("max_background_jobs=4;"), "got {}", s);
+        assert!(s.contains("max_open_files=100;"));
+        assert!(DBOptions::from_string(&DBOptions::default(), "max_open_files=many").is_err());
+    }
+
+    #[test]
+    fn options_clone() {
+        use cache::CacheBuilder;
+
+        let cache = CacheBuilder::new_lru(8 << 20).build().unwrap();
+        let opts = Options::default()
+            .map_db_options(|db| db.create_if_missing(true))
+            .map_cf_options(|cf| {
+                cf.write_buffer_size(4 << 20)
+                    .table_factory_block_based(BlockBasedTableOptions::default().block_cache(Some(cache.clone())))
+            });
+        let cloned = opts.clone().map_cf_options(|cf| cf.write_buffer_size(8 << 20));
+
+        assert!(format!("{}", ColumnFamilyOptions::from_options(&opts)).contains("write_buffer_size=4194304"));
+        assert!(format!("{}", ColumnFamilyOptions::from_options(&cloned)).contains("write_buffer_size=8388608"));
+
+        let cfopt = ColumnFamilyOptions::default().max_write_buffer_number(5);
+        let cfopt2 = cfopt.clone().max_write_buffer_number(7);
+        assert!(format!("{}", cfopt).contains("max_write_buffer_number=5"));
+        assert!(format!("{}", cfopt2).contains("max_write_buffer_number=7"));
+
+        let dbopt = DBOptions::default().allow_2pc(true);
+        let dbopt2 = dbopt.clone().allow_2pc(false);
+        assert!(format!("{}", dbopt).contains("allow_2pc=true"));
+        assert!(format!("{}", dbopt2).contains("allow_2pc=false"));
+
+        // block cache is shared, not duplicated
+        assert_eq!(cache.get_usage(), 0);
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(&cloned, &tmp_dir).unwrap();
+        assert!(db.put(&Default::default(), b"key", b"value").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"key").unwrap(), b"value".as_ref());
+        assert!(cache.get_usage() > 0);
+    }
+
+    #[test]
+    fn access_hint_on_compaction_start() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opts = Options::default().map_db_options(|db| {
+            db.create_if_missing(true)
+                .advise_random_on_open(false)
+                .access_hint_on_compaction_start(AccessHint::WillNeed)
+        });
+        assert!(format!("{}", DBOptions::default().advise_random_on_open(false)).contains("advise_random_on_open=false"));
+
+        let db = DB::open(&opts, &tmp_dir).unwrap();
+        for i in 0..10 {
+            let key = format!("k{}", i);
+            let val = format!("v{}", i * 10);
+            assert!(db.put(&Default::default(), key.as_bytes(), val.as_bytes()).is_ok());
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+
+        for i in 0..10 {
+            let key = format!("k{}", i);
+            let val = format!("v{}", i * 10);
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), val.as_bytes());
+        }
+    }
+
+    #[test]
+    fn max_sequential_skip_in_iterations() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.disable_auto_compactions(true)
+                        .max_sequential_skip_in_iterations(2)
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.put(&Default::default(), b"a", b"first").is_ok());
+        // many versions of the same key, forces a reseek when stepping over them
+        for i in 0..100 {
+            let val = format!("v{}", i);
+            assert!(db.put(&Default::default(), b"b", val.as_bytes()).is_ok());
+            if i == 50 {
+                assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            }
+        }
+        assert!(db.put(&Default::default(), b"c", b"last").is_ok());
+
+        let mut it = db.new_iterator(&ReadOptions::default());
+        it.seek_to_first();
+        assert_eq!(it.key(), b"a");
+        it.next();
+        assert_eq!(it.key(), b"b");
+        assert_eq!(it.value(), b"v99");
+        it.next();
+        assert_eq!(it.key(), b"c");
+        assert_eq!(it.value(), b"last");
+        it.next();
+        assert!(!it.is_valid());
+        assert!(it.status().is_ok());
+
+        it.seek_to_last();
+        it.prev();
+        assert_eq!(it.key(), b"b");
+        assert_eq!(it.value(), b"v99");
+    }
+
+    #[test]
+    fn readoptions_tailing() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        for i in 0..5 {
+            let key = format!("k{}", i);
+            assert!(db.put(&Default::default(), key.as_bytes(), b"val").is_ok());
+        }
+
+        let ropts = ReadOptions::default().tailing(true);
+        let mut it = db.new_iterator(&ropts);
+        it.seek(b"k");
+        let mut count = 0;
+        while it.is_valid() {
+            count += 1;
+            it.next();
+        }
+        assert_eq!(count, 5);
+
+        for i in 5..8 {
+            let key = format!("k{}", i);
+            assert!(db.put(&Default::default(), key.as_bytes(), b"val").is_ok());
+        }
+        // exhausted until re-seeked
+        assert!(!it.is_valid());
+
+        it.seek(b"k5");
+        let mut keys = vec![];
+        while it.is_valid() {
+            keys.push(it.key().to_vec());
+            it.next();
+        }
+        assert!(it.status().is_ok());
+        assert_eq!(keys, vec![b"k5", b"k6", b"k7"]);
+    }
+
     #[test]
     fn readoptions() {
         // FIXME: is disable block cache works?
@@ -3117,6 +3362,35 @@ mod tests {
         assert!(val.is_ok());
     }
 
+    #[test]
+    fn readoptions_iterate_upper_bound() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        for i in 0..10 {
+            let key = format!("k{}", i);
+            assert!(db.put(&Default::default(), key.as_bytes(), b"val").is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let upper_bound = b"k5".to_vec();
+        let ropts = ReadOptions::default().iterate_upper_bound(&upper_bound);
+        let mut it = db.new_iterator(&ropts);
+        it.seek_to_first();
+        let mut keys = vec![];
+        while it.is_valid() {
+            keys.push(it.key().to_vec());
+            it.next();
+        }
+        assert!(it.status().is_ok());
+        assert_eq!(keys, vec![b"k0", b"k1", b"k2", b"k3", b"k4"]);
+
+        // bound is exclusive, seek past it yields nothing
+        it.seek(b"k5");
+        assert!(!it.is_valid());
+        it.seek(b"k3");
+        assert_eq!(it.key(), b"k3");
+    }
+
     #[test]
     fn default_instance() {
         let w1 = WriteOptions::default_instance();
@@ -3163,4 +3437,292 @@ mod tests {
         assert_eq!(meta.levels[3].files.len(), 0);
         assert!(meta.levels[4].files.len() > 0);
     }
+
+    #[test]
+    fn compression_per_level() {
+        use convenience::get_supported_compressions;
+
+        let supported = get_supported_compressions();
+        let bottommost = if supported.contains(&CompressionType::ZSTD) {
+            CompressionType::ZSTD
+        } else if let Some(&c) = supported.iter().find(|&&c| c != CompressionType::NoCompression) {
+            c
+        } else {
+            return; // built without any compression library
+        };
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opts = Options::default()
+            .map_db_options(|db| db.create_if_missing(true))
+            .map_cf_options(|cf| {
+                cf.num_levels(2)
+                    .compression_per_level(&[CompressionType::NoCompression, bottommost])
+            });
+        let db = DB::open(&opts, &tmp_dir).unwrap();
+        let compressions = |db: &DB| {
+            db.get_properties_of_all_tables_cf(&db.default_column_family())
+                .unwrap()
+                .iter()
+                .map(|(_, prop)| prop.compression_name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        for i in 0..100 {
+            let key = format!("k{:03}", i);
+            let val = format!("v{:0100}", i);
+            assert!(db.put(&Default::default(), key.as_bytes(), val.as_bytes()).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        // level 0
+        assert_eq!(compressions(&db), vec!["NoCompression".to_string()]);
+
+        // into level 1
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        let meta = db.get_column_family_metadata(&db.default_column_family());
+        assert_eq!(meta.levels[1].files.len(), 1);
+        let names = compressions(&db);
+        assert_eq!(names.len(), 1);
+        assert!(names[0] != "NoCompression");
+
+        drop(db);
+        let db = DB::open(&opts, &tmp_dir).unwrap();
+        for i in 0..100 {
+            let key = format!("k{:03}", i);
+            let val = format!("v{:0100}", i);
+            assert_eq!(db.get(&Default::default(), key.as_bytes()).unwrap(), val.as_bytes());
+        }
+    }
+
+    #[test]
+    fn compression_opts_zstd_dictionary() {
+        use convenience::get_supported_compressions;
+
+        if !get_supported_compressions().contains(&CompressionType::ZSTD) {
+            return;
+        }
+
+        let sst_size_with_dict = |max_dict_bytes| {
+            let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+            let db = DB::open(
+                Options::default()
+                    .map_db_options(|db| db.create_if_missing(true))
+                    .map_cf_options(|cf| {
+                        // dictionary is only used when compacting into the bottommost level,
+                        // sampled from the first output file and used for the following ones
+                        cf.num_levels(2)
+                            .compression(CompressionType::ZSTD)
+                            .compression_opts(CompressionOptions {
+                                max_dict_bytes: max_dict_bytes,
+                                ..Default::default()
+                            })
+                            .target_file_size_base(64 << 10)
+                    }),
+                &tmp_dir,
+            ).unwrap();
+
+            // many small similar values
+            for i in 0..20000 {
+                let key = format!("user:{:08}", i);
+                let val = format!("{{\"id\":{},\"name\":\"user-{}\",\"active\":{}}}", i, i % 97, i % 2 == 0);
+                assert!(db.put(&Default::default(), key.as_bytes(), val.as_bytes()).is_ok());
+            }
+            assert!(db.compact_range(&Default::default(), ..).is_ok());
+            assert_eq!(
+                db.get(&Default::default(), b"user:00000042").unwrap(),
+                b"{\"id\":42,\"name\":\"user-42\",\"active\":true}".as_ref()
+            );
+            db.get_column_family_metadata(&db.default_column_family()).size
+        };
+
+        assert!(sst_size_with_dict(16 << 10) < sst_size_with_dict(0));
+    }
+
+    #[test]
+    fn wal_recovery_mode_torn_tail() {
+        use std::fs;
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(
+                Options::default().map_db_options(|db| db.create_if_missing(true).avoid_flush_during_shutdown(true)),
+                &tmp_dir,
+            ).unwrap();
+            for i in 0..10 {
+                let key = format!("key-{}", i);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+            }
+        }
+
+        // simulate a torn write of the last record
+        let wal = fs::read_dir(&tmp_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().map_or(false, |ext| ext == "log"))
+            .expect("WAL file is kept");
+        let wal_size = fs::metadata(&wal).unwrap().len();
+        fs::OpenOptions::new().write(true).open(&wal).unwrap().set_len(wal_size - 3).unwrap();
+
+        let open_with = |mode| {
+            DB::open(
+                Options::default().map_db_options(|db| db.wal_recovery_mode(mode)),
+                &tmp_dir,
+            )
+        };
+
+        // a torn tail is a corruption when demanding consistency
+        let ret = open_with(WALRecoveryMode::AbsoluteConsistency);
+        assert!(ret.is_err());
+
+        let db = open_with(WALRecoveryMode::PointInTimeRecovery).unwrap();
+        for i in 0..9 {
+            let key = format!("key-{}", i);
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), b"value".as_ref());
+        }
+        assert!(db.get(&ReadOptions::default(), b"key-9").unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn level_compaction_dynamic_level_bytes() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.compression(CompressionType::NoCompression)
+                        .write_buffer_size(64 << 10)
+                        .level_compaction_dynamic_level_bytes(true)
+                        .max_bytes_for_level_base(128 << 10)
+                        .max_bytes_for_level_multiplier(4.0)
+                        .target_file_size_base(64 << 10)
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        let value = vec![b'v'; 1000];
+        for i in 0..2000 {
+            let key = format!("key-{:06}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), &value).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+
+        // with dynamic level bytes, data lands in the last level first and the
+        // levels above it stay empty until the last level outgrows them
+        let meta = db.get_column_family_metadata(&db.default_column_family());
+        assert_eq!(meta.levels.len(), 7);
+        let (last, upper) = meta.levels.split_last().unwrap();
+        assert!(upper.iter().all(|lv| lv.files.is_empty()), "levels: {:?}", meta.levels);
+        assert_eq!(last.level, 6);
+        assert!(last.files.len() > 1);
+        assert_eq!(last.size, meta.size);
+
+        for i in (0..2000).step_by(100) {
+            let key = format!("key-{:06}", i);
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), &value[..]);
+        }
+    }
+
+    #[test]
+    fn compaction_style_fifo_max_table_files_size() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.compression(CompressionType::NoCompression)
+                        .disable_auto_compactions(true)
+                        .compaction_style(CompactionStyle::CompactionStyleFIFO)
+                        .compaction_options_fifo(
+                            CompactionOptionsFIFO::default()
+                                .max_table_files_size(350 << 10)
+                                .allow_compaction(false),
+                        )
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        // ~100KB per table file, oldest first
+        let value = vec![b'v'; 1000];
+        for round in 0..10 {
+            for i in 0..100 {
+                let key = format!("round-{}-{:03}", round, i);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), &value).is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+        assert_eq!(db.get_column_family_metadata(&db.default_column_family()).file_count, 10);
+
+        // drops the oldest table files until under max_table_files_size
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+
+        let meta = db.get_column_family_metadata(&db.default_column_family());
+        assert!(meta.file_count < 10);
+        assert!(meta.size <= 350 << 10);
+
+        assert!(db.get(&ReadOptions::default(), b"round-0-000").unwrap_err().is_not_found());
+        assert!(db.get(&ReadOptions::default(), b"round-0-099").unwrap_err().is_not_found());
+        assert_eq!(db.get(&ReadOptions::default(), b"round-9-000").unwrap(), &value[..]);
+        assert_eq!(db.get(&ReadOptions::default(), b"round-9-099").unwrap(), &value[..]);
+    }
+
+    #[test]
+    fn write_options_no_slowdown() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.write_buffer_size(64 << 10).max_write_buffer_number(2)),
+            &tmp_dir,
+        ).unwrap();
+
+        let value = vec![b'v'; 1024];
+        let wopts = WriteOptions::default().low_pri(true);
+        assert!(db.put(&wopts, b"low-pri", &value).is_ok());
+
+        // memtables can't be flushed, writes stop once they are all full
+        assert!(db.pause_background_work().is_ok());
+        let wopts = WriteOptions::default().no_slowdown(true);
+        let mut stall_error = None;
+        for i in 0..10000 {
+            let key = format!("key-{:05}", i);
+            if let Err(e) = db.put(&wopts, key.as_bytes(), &value) {
+                stall_error = Some(e);
+                break;
+            }
+        }
+        let err = stall_error.expect("write stalls with all memtables full");
+        assert_eq!(err.code(), ::error::Code::Incomplete);
+
+        assert!(db.continue_background_work().is_ok());
+        assert!(db.put(&WriteOptions::default(), b"after-stall", &value).is_ok());
+    }
+
+    #[test]
+    fn write_options_disable_wal() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(
+                Options::default().map_db_options(|db| db.create_if_missing(true).avoid_flush_during_shutdown(true)),
+                &tmp_dir,
+            ).unwrap();
+            let cf = db.create_column_family(&ColumnFamilyOptions::default(), "dropped").unwrap();
+
+            assert!(db.put(&WriteOptions::default(), b"wal-key", b"value").is_ok());
+            assert!(db.put(&WriteOptions::default().disable_wal(true), b"no-wal-key", b"value").is_ok());
+            assert_eq!(db.get(&ReadOptions::default(), b"no-wal-key").unwrap(), b"value".as_ref());
+
+            let mut batch = WriteBatch::new();
+            batch.put_cf(&cf, b"cf-key", b"value").put(b"batch-key", b"value");
+            assert!(db.drop_column_family(&cf).is_ok());
+            assert!(db.write(&WriteOptions::default(), batch.clone()).is_err());
+            let wopts = WriteOptions::default().ignore_missing_column_families(true);
+            assert!(db.write(&wopts, batch).is_ok());
+            // closed without flushing the memtable
+        }
+
+        let db = DB::open(Options::default(), &tmp_dir).unwrap();
+        assert_eq!(db.get(&ReadOptions::default(), b"wal-key").unwrap(), b"value".as_ref());
+        assert_eq!(db.get(&ReadOptions::default(), b"batch-key").unwrap(), b"value".as_ref());
+        assert!(db.get(&ReadOptions::default(), b"no-wal-key").unwrap_err().is_not_found());
+    }
 }
@@ -0,0 +1,149 @@
+//! Utility functions to load the options persisted in an options file.
+//!
+//! A DB writes its options to an `OPTIONS-xxxxxx` file in the DB directory
+//! when it is opened, and whenever they are changed with `set_options`.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::ptr;
+
+use rocks_sys as ll;
+
+use env::Env;
+use error::Status;
+use options::{ColumnFamilyOptions, DBOptions};
+use to_raw::{FromRaw, ToRaw};
+
+use super::Result;
+
+unsafe fn options_from_ll(
+    db_options: *mut ll::rocks_dboptions_t,
+    num_cfs: usize,
+    cf_names: *mut *mut c_char,
+    cf_options: *mut *mut ll::rocks_cfoptions_t,
+) -> (DBOptions, Vec<(String, ColumnFamilyOptions)>) {
+    let mut cfs = Vec::with_capacity(num_cfs);
+    for i in 0..num_cfs {
+        let name = CStr::from_ptr(*cf_names.offset(i as isize)).to_string_lossy().into_owned();
+        cfs.push((name, ColumnFamilyOptions::from_ll(*cf_options.offset(i as isize))));
+    }
+    ll::rocks_db_list_column_families_destroy(cf_names, num_cfs);
+    ll::rocks_load_options_cf_options_list_destroy(cf_options);
+    (DBOptions::from_ll(db_options), cfs)
+}
+
+/// Constructs the `DBOptions` and the list of column family names and
+/// `ColumnFamilyOptions` from the latest options file of the DB at `dbpath`.
+///
+/// Note that all the pointer options (except table_factory, which will
+/// be described in more details below) will be initialized with the default
+/// values, such as comparator, merge operator and compaction filter. Only
+/// the built-in table factories are loaded.
+///
+/// * `ignore_unknown_options` - If true, options unknown to this version of
+///    RocksDB are ignored instead of returning an InvalidArgument error.
+pub fn load_latest_options<P: AsRef<Path>>(
+    dbpath: P,
+    env: &Env,
+    ignore_unknown_options: bool,
+) -> Result<(DBOptions, Vec<(String, ColumnFamilyOptions)>)> {
+    let dbpath = dbpath.as_ref().to_str().expect("valid path");
+    let mut num_cfs = 0;
+    let mut cf_names = ptr::null_mut();
+    let mut cf_options = ptr::null_mut();
+    let mut status = ptr::null_mut::<ll::rocks_status_t>();
+    unsafe {
+        let db_options = ll::rocks_load_latest_options(
+            dbpath.as_ptr() as *const _,
+            dbpath.len(),
+            env.raw(),
+            ignore_unknown_options as u8,
+            &mut num_cfs,
+            &mut cf_names,
+            &mut cf_options,
+            &mut status,
+        );
+        Status::from_ll(status).map(|()| options_from_ll(db_options, num_cfs, cf_names, cf_options))
+    }
+}
+
+/// Similar to `load_latest_options`, but loads from the options file at
+/// `options_file_name`.
+pub fn load_options_from_file<P: AsRef<Path>>(
+    options_file_name: P,
+    env: &Env,
+    ignore_unknown_options: bool,
+) -> Result<(DBOptions, Vec<(String, ColumnFamilyOptions)>)> {
+    let options_file_name = options_file_name.as_ref().to_str().expect("valid path");
+    let mut num_cfs = 0;
+    let mut cf_names = ptr::null_mut();
+    let mut cf_options = ptr::null_mut();
+    let mut status = ptr::null_mut::<ll::rocks_status_t>();
+    unsafe {
+        let db_options = ll::rocks_load_options_from_file(
+            options_file_name.as_ptr() as *const _,
+            options_file_name.len(),
+            env.raw(),
+            ignore_unknown_options as u8,
+            &mut num_cfs,
+            &mut cf_names,
+            &mut cf_options,
+            &mut status,
+        );
+        Status::from_ll(status).map(|()| options_from_ll(db_options, num_cfs, cf_names, cf_options))
+    }
+}
+
+/// Returns the latest options file name under the DB directory `dbpath`.
+pub fn get_latest_options_file_name<P: AsRef<Path>>(dbpath: P, env: &Env) -> Result<String> {
+    let dbpath = dbpath.as_ref().to_str().expect("valid path");
+    let mut options_file_name = String::new();
+    let mut status = ptr::null_mut::<ll::rocks_status_t>();
+    unsafe {
+        ll::rocks_get_latest_options_file_name(
+            dbpath.as_ptr() as *const _,
+            dbpath.len(),
+            env.raw(),
+            &mut options_file_name as *mut String as *mut _,
+            &mut status,
+        );
+        Status::from_ll(status).map(|()| options_file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn load_persisted_options() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(
+                Options::default()
+                    .map_db_options(|db| db.create_if_missing(true).max_background_jobs(3))
+                    .map_cf_options(|cf| cf.write_buffer_size(1 << 20)),
+                &tmp_dir,
+            ).unwrap();
+            let cfopts = ColumnFamilyOptions::default().max_write_buffer_number(5);
+            assert!(db.create_column_family(&cfopts, "events").is_ok());
+        }
+
+        let env = Env::default_instance();
+        let (db_options, cfs) = load_latest_options(&tmp_dir, env, false).unwrap();
+        assert!(db_options.to_string().contains("max_background_jobs=3;"));
+        assert_eq!(cfs.iter().map(|&(ref name, _)| name.as_str()).collect::<Vec<_>>(), vec!["default", "events"]);
+        assert!(cfs[0].1.to_string().contains("write_buffer_size=1048576;"));
+        assert!(cfs[1].1.to_string().contains("max_write_buffer_number=5;"));
+
+        let name = get_latest_options_file_name(&tmp_dir, env).unwrap();
+        assert!(name.starts_with("OPTIONS-"));
+        let (db_options, cfs) = load_options_from_file(tmp_dir.path().join(&name), env, false).unwrap();
+        assert!(db_options.to_string().contains("max_background_jobs=3;"));
+        assert_eq!(cfs.len(), 2);
+
+        assert!(load_options_from_file(tmp_dir.path().join("OPTIONS-missing"), env, false).is_err());
+    }
+}
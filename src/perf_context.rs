@@ -196,4 +196,30 @@ mod tests {
         stat.reset();
         assert_eq!(stat.user_key_comparison_count, 0);
     }
+
+    #[test]
+    fn perf_context_enable_count() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        for i in 0..100 {
+            let key = format!("key-{:03}", i);
+            assert!(db.put(&Default::default(), key.as_bytes(), b"value").is_ok());
+        }
+
+        set_perf_level(PerfLevel::EnableCount);
+        let stat = PerfContext::current();
+        stat.reset();
+
+        assert_eq!(db.get(&Default::default(), b"key-042").unwrap(), b"value".as_ref());
+
+        assert!(stat.user_key_comparison_count > 0);
+        assert_eq!(stat.get_from_memtable_count, 1);
+        // time stats are not collected at this level
+        assert_eq!(stat.get_from_memtable_time, 0);
+
+        set_perf_level(PerfLevel::Disable);
+        stat.reset();
+        assert!(db.get(&Default::default(), b"key-042").is_ok());
+        assert_eq!(stat.user_key_comparison_count, 0);
+    }
 }
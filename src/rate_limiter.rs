@@ -25,6 +25,13 @@ impl ToRaw<ll::rocks_ratelimiter_t> for RateLimiter {
     }
 }
 
+impl Clone for RateLimiter {
+    /// Duplicated RateLimiter inner shared_ptr
+    fn clone(&self) -> Self {
+        RateLimiter { raw: unsafe { ll::rocks_ratelimiter_clone(self.raw) } }
+    }
+}
+
 impl RateLimiter {
     /// Create a RateLimiter object, which can be shared among RocksDB instances to
     /// control write rate of flush and compaction.
@@ -49,4 +56,70 @@ impl RateLimiter {
     pub fn new(rate_bytes_per_sec: i64, refill_period_us: i64, fairness: i32) -> RateLimiter {
         RateLimiter { raw: unsafe { ll::rocks_ratelimiter_create(rate_bytes_per_sec, refill_period_us, fairness) } }
     }
+
+    /// This API allows user to dynamically change rate limiter's bytes per second.
+    /// REQUIRED: bytes_per_second > 0
+    pub fn set_bytes_per_second(&self, bytes_per_second: i64) {
+        assert!(bytes_per_second > 0, "bytes_per_second must be positive");
+        unsafe { ll::rocks_ratelimiter_set_bytes_per_second(self.raw, bytes_per_second) }
+    }
+
+    /// Max bytes can be granted in a single burst
+    pub fn get_single_burst_bytes(&self) -> i64 {
+        unsafe { ll::rocks_ratelimiter_get_single_burst_bytes(self.raw) }
+    }
+
+    /// Total bytes that go though rate limiter
+    pub fn get_total_bytes_through(&self) -> i64 {
+        unsafe { ll::rocks_ratelimiter_get_total_bytes_through(self.raw) }
+    }
+
+    /// Total # of requests that go though rate limiter
+    pub fn get_total_requests(&self) -> i64 {
+        unsafe { ll::rocks_ratelimiter_get_total_requests(self.raw) }
+    }
+
+    /// Current write rate limit of flush and compaction
+    pub fn get_bytes_per_second(&self) -> i64 {
+        unsafe { ll::rocks_ratelimiter_get_bytes_per_second(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn rate_limiter_bytes_through() {
+        let limiter = RateLimiter::new(1 << 20, 100_000, 10);
+        assert_eq!(limiter.get_bytes_per_second(), 1 << 20);
+        assert_eq!(limiter.get_total_bytes_through(), 0);
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true).rate_limiter(Some(limiter.clone())))
+                .map_cf_options(|cf| cf.disable_auto_compactions(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..4 {
+            for j in 0..1000 {
+                let key = format!("key-{:04}", j);
+                let value = format!("value-{}-{}", i, j);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), value.as_bytes()).is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+        let flushed = limiter.get_total_bytes_through();
+        assert!(flushed > 0);
+        assert!(limiter.get_total_requests() > 0);
+
+        // retune at runtime, then compact
+        limiter.set_bytes_per_second(4 << 20);
+        assert_eq!(limiter.get_bytes_per_second(), 4 << 20);
+        assert!(db.compact_range(&Default::default(), ..).is_ok());
+        assert!(limiter.get_total_bytes_through() > flushed);
+    }
 }
@@ -4,6 +4,7 @@ use std::fmt;
 use std::slice;
 use std::ops;
 use std::str;
+use std::string::FromUtf8Error;
 
 use rocks_sys as ll;
 
@@ -27,6 +28,52 @@ impl CVec<u8> {
     pub fn to_str(&self) -> Result<&str, str::Utf8Error> {
         str::from_utf8(self)
     }
+
+    /// Copies into an owned `String`, fails on invalid UTF-8
+    pub fn into_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.to_vec())
+    }
+}
+
+impl<T: Copy> IntoIterator for CVec<T> {
+    type Item = T;
+    type IntoIter = CVecIntoIter<T>;
+
+    fn into_iter(self) -> CVecIntoIter<T> {
+        CVecIntoIter { vec: self, pos: 0 }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator that moves out of a `CVec`
+pub struct CVecIntoIter<T> {
+    vec: CVec<T>,
+    pos: usize,
+}
+
+impl<T: Copy> Iterator for CVecIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let item = self.vec.get(self.pos).cloned();
+        if item.is_some() {
+            self.pos += 1;
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remain = self.vec.len() - self.pos;
+        (remain, Some(remain))
+    }
 }
 
 impl<T: fmt::Debug> fmt::Debug for CVec<T> {
@@ -62,9 +109,10 @@ impl<'a, T: PartialEq> PartialEq<&'a [T]> for CVec<T> {
     }
 }
 
-impl<'a, 'b, T: PartialEq> PartialEq<&'b [T]> for &'a CVec<T> {
-    fn eq(&self, rhs: &&[T]) -> bool {
-        &self.as_ref() == rhs
+// also gives `&CVec<T> == &[T]` via the reference impl in core
+impl<T: PartialEq> PartialEq<[T]> for CVec<T> {
+    fn eq(&self, rhs: &[T]) -> bool {
+        self.as_ref() == rhs
     }
 }
 
@@ -105,6 +153,13 @@ impl PinnableSlice {
     pub fn size(&self) -> usize {
         unsafe { ll::rocks_pinnable_slice_size(self.raw) as usize }
     }
+
+    /// Releases the pinned data and clears the slice, so it can be reused.
+    pub fn reset(&mut self) {
+        unsafe {
+            ll::rocks_pinnable_slice_reset(self.raw);
+        }
+    }
 }
 
 
@@ -150,6 +205,51 @@ impl<'a, 'b> PartialEq<&'b [u8]> for &'a PinnableSlice {
 mod tests {
     use super::*;
 
+    #[test]
+    fn cvec_as_slice() {
+        use super::super::rocksdb::*;
+
+        fn byte_len(data: &[u8]) -> usize {
+            data.len()
+        }
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        assert!(db.put(&WriteOptions::default(), b"key", b"value").is_ok());
+
+        // CVec is returned by multi_get and DbIterator
+        let cvec = db.multi_get(&ReadOptions::default(), &[b"key"]).remove(0).unwrap();
+        assert!(&*cvec == b"value");
+        assert!(cvec == b"value".as_ref());
+        assert!(cvec == *b"value".as_ref());
+        assert_eq!(byte_len(cvec.as_ref()), 5);
+        assert_eq!(byte_len(&cvec), 5);
+        assert_eq!(cvec.to_str(), Ok("value"));
+    }
+
+    #[test]
+    fn cvec_into_iter() {
+        use super::super::rocksdb::*;
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(Options::default().map_db_options(|db| db.create_if_missing(true)), &tmp_dir).unwrap();
+        assert!(db.put(&WriteOptions::default(), b"key", b"value").is_ok());
+        assert!(db.put(&WriteOptions::default(), b"bad", b"\xff\xfe").is_ok());
+
+        let mut vals = db.multi_get(&ReadOptions::default(), &[b"key", b"bad"]).into_iter();
+        let cvec = vals.next().unwrap().unwrap();
+        assert_eq!((&cvec).into_iter().count(), 5);
+        assert_eq!(cvec.to_vec(), b"value");
+        let bytes = cvec.into_iter().collect::<Vec<u8>>();
+        assert_eq!(bytes, b"value");
+
+        let cvec = vals.next().unwrap().unwrap();
+        assert!(cvec.into_string().is_err());
+
+        let cvec = db.multi_get(&ReadOptions::default(), &[b"key"]).remove(0).unwrap();
+        assert_eq!(cvec.into_string().unwrap(), "value");
+    }
+
     #[test]
     fn pinnable_slice() {
         let s = PinnableSlice::new();
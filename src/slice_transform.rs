@@ -41,6 +41,17 @@ pub trait SliceTransform {
     }
 }
 
+/// A plain function or closure can be used as a transform, taking every key as
+/// in domain. It must handle keys shorter than the prefix it extracts.
+impl<F> SliceTransform for F
+where
+    F: for<'a> Fn(&'a [u8]) -> &'a [u8],
+{
+    fn transform<'a>(&self, key: &'a [u8]) -> &'a [u8] {
+        (*self)(key)
+    }
+}
+
 // rust -> c part
 #[doc(hidden)]
 pub mod c {
@@ -155,6 +166,38 @@ mod tests {
     }
 
 
+    /// Opens a DB in `tmp_dir` with the column family options, e.g. the prefix extractor, set by `f`
+    fn open_prefix_db<'a, F>(tmp_dir: &::tempdir::TempDir, f: F) -> DB<'a>
+    where
+        F: FnOnce(ColumnFamilyOptions) -> ColumnFamilyOptions,
+    {
+        DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(f),
+            tmp_dir,
+        ).unwrap()
+    }
+
+    fn put_keys<K: AsRef<[u8]>>(db: &DB, keys: &[K]) {
+        for key in keys {
+            assert!(db.put(&WriteOptions::default(), key.as_ref(), b"23333").is_ok());
+        }
+    }
+
+    /// Seeks to `target` and collects keys until the iterator is exhausted
+    fn seek_keys(db: &DB, options: &ReadOptions, target: &[u8]) -> Vec<String> {
+        let mut it = db.new_iterator(options);
+        it.seek(target);
+        let mut keys = vec![];
+        while it.is_valid() {
+            keys.push(String::from_utf8_lossy(it.key()).to_string());
+            it.next();
+        }
+        assert!(it.status().is_ok());
+        keys
+    }
+
     #[test]
     fn prefix_extractor_capped() {
         let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
@@ -213,4 +256,84 @@ mod tests {
         assert!(keys.contains(&"abc-002".to_string()));
         assert!(!keys.contains(&"def-000".to_string()));
     }
+
+    #[test]
+    fn prefix_extractor_fixed_total_order_seek() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = open_prefix_db(&tmp_dir, |cf| {
+            cf.prefix_extractor_fixed(3)
+                .memtable_prefix_bloom_size_ratio(0.1) // enable prefix bloom filter
+        });
+
+        let mut expected = vec![];
+        for prefix in &["aaa", "bbb", "ccc"] {
+            let keys = (0..5).map(|i| format!("{}-{:03}", prefix, i)).collect::<Vec<_>>();
+            put_keys(&db, &keys);
+            expected.extend(keys);
+            // half in sst files, half in memtable
+            if *prefix != "ccc" {
+                assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            }
+        }
+
+        // prefix-bounded mode stops at the prefix boundary
+        let keys = seek_keys(&db, &ReadOptions::default().prefix_same_as_start(true), b"bbb-");
+        assert_eq!(keys, &expected[5..10]);
+
+        // total order seek bypasses the prefix bloom, crossing prefixes
+        let keys = seek_keys(&db, &ReadOptions::default().total_order_seek(true), b"aaa-");
+        assert_eq!(keys, expected);
+
+        // seek to a prefix which has no keys at all
+        let keys = seek_keys(&db, &ReadOptions::default().total_order_seek(true), b"abz");
+        assert_eq!(keys, &expected[5..]);
+    }
+
+    #[test]
+    fn prefix_seek_skips_missing_prefix() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = open_prefix_db(&tmp_dir, |cf| {
+            cf.prefix_extractor_fixed(3)
+                .memtable_prefix_bloom_size_ratio(0.1) // enable prefix bloom filter
+        });
+
+        // all in memtable, no key has the "bbb" prefix
+        put_keys(&db, &["aaa-001", "aaa-002", "ccc-001", "ccc-002"]);
+
+        // prefix bloom filter rules out "bbb", default prefix seek finds nothing
+        let mut it = db.new_iterator(&ReadOptions::default());
+        it.seek(b"bbb-000");
+        assert!(!it.is_valid());
+
+        // total order seek ignores the prefix extractor
+        let keys = seek_keys(&db, &ReadOptions::default().total_order_seek(true), b"bbb-000");
+        assert_eq!(keys, vec!["ccc-001", "ccc-002"]);
+
+        // prefix_same_as_start stops at the end of the seek prefix
+        let keys = seek_keys(&db, &ReadOptions::default().prefix_same_as_start(true), b"aaa-000");
+        assert_eq!(keys, vec!["aaa-001", "aaa-002"]);
+    }
+
+    fn first_4_bytes(key: &[u8]) -> &[u8] {
+        &key[..4.min(key.len())]
+    }
+
+    #[test]
+    fn prefix_extractor_fixed_and_fn() {
+        let expected = vec!["user-0", "user-1", "user-2", "user-3", "userX"];
+        let fixed = |cf: ColumnFamilyOptions| cf.prefix_extractor_fixed(4);
+        let func = |cf: ColumnFamilyOptions| cf.prefix_extractor(Box::new(first_4_bytes));
+        let setups: Vec<&Fn(ColumnFamilyOptions) -> ColumnFamilyOptions> = vec![&fixed, &func];
+
+        for setup in setups {
+            let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+            let db = open_prefix_db(&tmp_dir, setup);
+            put_keys(&db, &["item-1", "user-3", "user-1", "userX", "usr-9", "user-2", "zzzz"]);
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+            put_keys(&db, &["user-0"]);
+
+            let keys = seek_keys(&db, &ReadOptions::default().prefix_same_as_start(true), b"user");
+            assert_eq!(keys, expected);
+        }
+    }
 }
@@ -2,34 +2,165 @@
 //! deletion rate.
 
 use std::path::Path;
+use std::ptr;
+
+use rocks_sys as ll;
 
 use env::Env;
 use env::Logger;
+use error::Status;
+use to_raw::ToRaw;
+
 use super::Result;
 
 /// SstFileManager is used to track SST files in the DB and control there
 /// deletion rate.
 ///
 /// All SstFileManager public functions are thread-safe.
-pub struct SstFileManager;
+pub struct SstFileManager {
+    raw: *mut ll::rocks_sst_file_manager_t,
+}
 
 unsafe impl Sync for SstFileManager {}
 unsafe impl Send for SstFileManager {}
 
+impl Drop for SstFileManager {
+    fn drop(&mut self) {
+        unsafe {
+            ll::rocks_sst_file_manager_destroy(self.raw);
+        }
+    }
+}
+
+impl ToRaw<ll::rocks_sst_file_manager_t> for SstFileManager {
+    fn raw(&self) -> *mut ll::rocks_sst_file_manager_t {
+        self.raw
+    }
+}
+
+impl Clone for SstFileManager {
+    /// Duplicated SstFileManager inner shared_ptr
+    fn clone(&self) -> Self {
+        SstFileManager { raw: unsafe { ll::rocks_sst_file_manager_clone(self.raw) } }
+    }
+}
+
 impl SstFileManager {
+    /// Create a new SstFileManager that can be shared among multiple RocksDB
+    /// instances to track SST file and control there deletion rate.
+    ///
+    /// * `env` - Pointer to Env object, please see "rocksdb/env.h".
+    /// * `info_log` - If not nullptr, info_log will be used to log errors.
+    ///
+    /// == Deletion rate limiting specific arguments ==
+    ///
+    /// * `trash_dir` - Path to the directory where deleted files will be moved
+    ///    to be deleted in a background thread while applying rate limiting. If this
+    ///    directory doesn't exist, it will be created. This directory should not be
+    ///    used by any other process or any other SstFileManager, Set to "" to
+    ///    disable deletion rate limiting.
+    /// * `rate_bytes_per_sec` - How many bytes should be deleted per second, If this
+    ///    value is set to 1024 (1 Kb / sec) and we deleted a file of size 4 Kb
+    ///    in 1 second, we will wait for another 3 seconds before we delete other
+    ///    files, Set to 0 to disable deletion rate limiting.
+    /// * `delete_existing_trash` - If set to true, the newly created
+    ///    SstFileManager will delete files that already exist in trash_dir.
     pub fn new<P: AsRef<Path>>(
-        env: &Env,
+        env: &'static Env,
         info_log: Option<&Logger>,
         trash_dir: P,
         rate_bytes_per_sec: i64,
         delete_existing_trash: bool,
     ) -> Result<SstFileManager> {
-        unimplemented!()
+        let trash_dir = trash_dir.as_ref().to_str().expect("valid path");
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_sst_file_manager_create(
+                env.raw(),
+                info_log.map(|l| l.raw()).unwrap_or_else(ptr::null_mut),
+                trash_dir.as_bytes().as_ptr() as *const _,
+                trash_dir.len(),
+                rate_bytes_per_sec,
+                delete_existing_trash as u8,
+                &mut status,
+            );
+            Status::from_ll(status).map(|()| SstFileManager { raw: raw })
+        }
+    }
+
+    /// Update the maximum allowed space that should be used by RocksDB, if
+    /// the total size of the SST files exceeds `max_allowed_space`, writes to
+    /// RocksDB will fail.
+    ///
+    /// Setting `max_allowed_space` to 0 will disable this feature, maximum allowed
+    /// space will be infinite (Default value).
+    pub fn set_max_allowed_space_usage(&self, max_allowed_space: u64) {
+        unsafe {
+            ll::rocks_sst_file_manager_set_max_allowed_space_usage(self.raw, max_allowed_space);
+        }
+    }
+
+    /// Return true if the total size of SST files exceeded the maximum allowed
+    /// space usage.
+    pub fn is_max_allowed_space_reached(&self) -> bool {
+        unsafe { ll::rocks_sst_file_manager_is_max_allowed_space_reached(self.raw) != 0 }
+    }
+
+    /// Return the total size of all tracked files.
+    pub fn get_total_size(&self) -> u64 {
+        unsafe { ll::rocks_sst_file_manager_get_total_size(self.raw) }
+    }
+
+    /// Return delete rate limit in bytes per second.
+    pub fn get_delete_rate_bytes_per_second(&self) -> i64 {
+        unsafe { ll::rocks_sst_file_manager_get_delete_rate_bytes_per_second(self.raw) }
+    }
+
+    /// Update the delete rate limit in bytes per second.
+    /// zero means disable delete rate limiting and delete files immediately
+    pub fn set_delete_rate_bytes_per_second(&self, delete_rate: i64) {
+        unsafe {
+            ll::rocks_sst_file_manager_set_delete_rate_bytes_per_second(self.raw, delete_rate);
+        }
     }
 }
 
-// extern SstFileManager* NewSstFileManager(
-// Env* env, std::shared_ptr<Logger> info_log = nullptr,
-// std::string trash_dir = "", int64_t rate_bytes_per_sec = 0,
-// bool delete_existing_trash = true, Status* status = nullptr);
-//
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn sst_file_manager_max_allowed_space() {
+        let manager = SstFileManager::new(Env::default_instance(), None, "", 0, false).unwrap();
+        assert_eq!(manager.get_total_size(), 0);
+        assert!(!manager.is_max_allowed_space_reached());
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true).sst_file_manager(Some(manager.clone()))),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"key-0", b"value").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        let total_size = manager.get_total_size();
+        assert!(total_size > 0);
+
+        // the next flush crosses the cap, and sets the background error
+        manager.set_max_allowed_space_usage(total_size + 1);
+        let mut space_error = None;
+        for i in 1..10 {
+            let key = format!("key-{}", i);
+            let ret = db.put(&WriteOptions::default(), key.as_bytes(), b"value")
+                .and_then(|()| db.flush(&FlushOptions::default().wait(true)));
+            if let Err(e) = ret {
+                space_error = Some(e);
+                break;
+            }
+        }
+        assert!(manager.is_max_allowed_space_reached());
+        let err = space_error.expect("writes fail when the max allowed space is reached");
+        assert!(format!("{:?}", err).contains("space"), "got {:?}", err);
+    }
+}
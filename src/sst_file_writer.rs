@@ -137,6 +137,17 @@ impl SstFileWriter {
         }
     }
 
+    /// Like `open`, but fails with InvalidArgument if "file_path" already exists,
+    /// instead of truncating it.
+    ///
+    /// Rust: the check is done before opening, it does not guard against concurrent creation.
+    pub fn open_new<P: AsRef<Path>>(&self, file_path: P) -> Result<()> {
+        if file_path.as_ref().exists() {
+            return Err(Status::with_message("sst file already exists"));
+        }
+        self.open(file_path)
+    }
+
     /// Add a Put key with value to currently opened file
     ///
     /// REQUIRES: key is after any previously added key according to comparator.
@@ -215,11 +226,34 @@ pub struct SstFileWriterBuilder {
 }
 
 impl SstFileWriterBuilder {
+    /// Options of the file, e.g. the table factory and comparator, should match the
+    /// column family the file is ingested into.
+    pub fn options(&mut self, options: Options) -> &mut Self {
+        self.options = Some(options);
+        self
+    }
+
     pub fn column_family(&mut self, cf: &ColumnFamilyHandle) -> &mut Self {
         self.column_family = cf.raw();
         self
     }
 
+    /// Use the builtin bytewise comparator, reversed if `val` is true.
+    ///
+    /// Must match the comparator of the column family the file is ingested into,
+    /// see `ColumnFamilyOptions::bitwise_comparator_reversed`.
+    pub fn bitwise_comparator_reversed(&mut self, val: bool) -> &mut Self {
+        self.c_comparator = unsafe {
+            if val {
+                ll::rocks_comparator_bytewise_reversed()
+            } else {
+                ll::rocks_comparator_bytewise()
+            }
+        };
+        self.use_rust_comparator = false;
+        self
+    }
+
     pub fn build(&mut self) -> SstFileWriter {
         let env_options = self.env_options.take().unwrap_or_default();
         let options = self.options.take().unwrap_or_default();
@@ -284,4 +318,25 @@ mod tests {
         let ret = writer.put(b"0000000", b"hello rust");
         assert!(ret.is_err()); // "Keys must be added in order"
     }
+
+    #[test]
+    fn sst_file_open_new() {
+        let sst_dir = ::tempdir::TempDir::new_in(".", "sst").unwrap();
+        let path = sst_dir.path().join("./23333.sst");
+
+        let writer = SstFileWriter::builder().build();
+        assert!(writer.open_new(&path).is_ok());
+        assert!(writer.put(b"0000001", b"hello world").is_ok());
+        let info = writer.finish().unwrap();
+        assert_eq!(info.num_entries(), 1);
+
+        let writer = SstFileWriter::builder().build();
+        let ret = writer.open_new(&path);
+        assert!(ret.is_err());
+        assert_eq!(ret.unwrap_err().code(), ::error::Code::InvalidArgument);
+
+        assert!(writer.open_new(sst_dir.path().join("./23334.sst")).is_ok());
+        // existing `open` still truncates
+        assert!(writer.open(&path).is_ok());
+    }
 }
@@ -604,4 +604,45 @@ mod tests {
         // a multiline string
         assert!(stat.get_histogram_string(Histograms::BytesPerRead).len() > 100);
     }
+
+    #[test]
+    fn statistics_tickers_and_reset_stats() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+
+        let stat = Statistics::new();
+        let db = DB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true).statistics(Some(stat.clone()))),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..100 {
+            let key = format!("k{}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        for i in 0..100 {
+            let key = format!("k{}", i);
+            assert!(db.get(&ReadOptions::default(), key.as_bytes()).is_ok());
+        }
+
+        assert_eq!(stat.get_ticker_count(Tickers::NumberKeysWritten), 100);
+        assert_eq!(stat.get_ticker_count(Tickers::NumberKeysRead), 100);
+        assert!(stat.get_ticker_count(Tickers::BytesWritten) > 100 * 5);
+        // first read of the only data block misses, the rest hit
+        assert!(stat.get_ticker_count(Tickers::BlockCacheHit) > 0);
+
+        let get_hist = stat.histogram_data(Histograms::DbGet);
+        assert!(get_hist.max > 0.0);
+        assert!(get_hist.median <= get_hist.percentile95);
+        assert!(get_hist.percentile95 <= get_hist.percentile99);
+        assert!(stat.histogram_data(Histograms::DbWrite).average > 0.0);
+
+        let db_stats = db.get_property("rocksdb.dbstats").unwrap();
+        assert!(db_stats.contains("Cumulative writes: 100 writes"), "{}", db_stats);
+        assert!(db.reset_stats().is_ok());
+        let db_stats = db.get_property("rocksdb.dbstats").unwrap();
+        assert!(db_stats.contains("Cumulative writes: 0 writes"), "{}", db_stats);
+        // statistics object is owned by the user, not reset
+        assert_eq!(stat.get_ticker_count(Tickers::NumberKeysWritten), 100);
+    }
 }
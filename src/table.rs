@@ -574,3 +574,186 @@ impl CuckooTableOptions {
         self
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+    use cache::CacheBuilder;
+
+    #[test]
+    fn block_based_table_shared_cache() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let cache = CacheBuilder::new_lru(8 << 20).build().unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.table_factory_block_based(
+                        BlockBasedTableOptions::default()
+                            .block_size(1024)
+                            .block_cache(Some(cache.clone()))
+                            .cache_index_and_filter_blocks(true)
+                            .pin_l0_filter_and_index_blocks_in_cache(true)
+                            .whole_key_filtering(true)
+                            .format_version(2),
+                    )
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..1000 {
+            let key = format!("key-{:04}", i);
+            let val = format!("val-{:0100}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), val.as_bytes()).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let props = db.get_properties_of_all_tables_cf(&db.default_column_family()).unwrap();
+        // ~110 KiB of data in 1 KiB blocks
+        assert!(props.iter().map(|(_, prop)| prop.num_data_blocks()).sum::<u64>() > 50);
+
+        let usage_before_reads = cache.get_usage();
+        for i in 0..1000 {
+            let key = format!("key-{:04}", i);
+            assert!(db.get(&ReadOptions::default(), key.as_bytes()).is_ok());
+        }
+        assert!(cache.get_usage() > usage_before_reads + 50 * 1024);
+    }
+
+    #[test]
+    fn partitioned_index_and_filters() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        // small enough to keep evicting index, filter and data blocks
+        let cache = CacheBuilder::new_lru(64 << 10).num_shard_bits(0).build().unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.table_factory_block_based(
+                        BlockBasedTableOptions::default()
+                            .block_size(256)
+                            .block_cache(Some(cache.clone()))
+                            .cache_index_and_filter_blocks(true)
+                            .index_type(IndexType::TwoLevelIndexSearch)
+                            // requires full filters
+                            .filter_policy(Some(FilterPolicy::new_bloom_filter(10, false)))
+                            .partition_filters(true)
+                            .metadata_block_size(512),
+                    )
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..10000 {
+            let key = format!("key-{:06}", i);
+            let val = format!("val-{:050}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), val.as_bytes()).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let props = db.get_properties_of_all_tables_cf(&db.default_column_family()).unwrap();
+        // thousands of index entries, cut into many 512 byte partitions
+        assert!(props.iter().map(|(_, prop)| prop.num_data_blocks()).sum::<u64>() > 1000);
+        assert!(props.iter().map(|(_, prop)| prop.index_size()).sum::<u64>() > 10 * 512);
+
+        for i in (0..10000).rev() {
+            let key = format!("key-{:06}", i);
+            let val = format!("val-{:050}", i);
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), val.as_bytes());
+        }
+        assert!(db.get(&ReadOptions::default(), b"key-010000").unwrap_err().is_not_found());
+    }
+
+    /// Writes 100 fixed-size key/value pairs with `opts`, flushes them into a table file,
+    /// then reopens the DB and reads every key back.
+    fn fixed_size_round_trip(opts: &Options) {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        {
+            let db = DB::open(opts, &tmp_dir).unwrap();
+            for i in 0..100 {
+                let key = format!("key-{:04}", i);
+                let val = format!("val-{:04}", i);
+                assert!(db.put(&WriteOptions::default(), key.as_bytes(), val.as_bytes()).is_ok());
+            }
+            assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+        }
+
+        let db = DB::open(opts, &tmp_dir).unwrap();
+        let props = db.get_properties_of_all_tables_cf(&db.default_column_family()).unwrap();
+        assert_eq!(props.iter().map(|(_, prop)| prop.num_entries()).sum::<u64>(), 100);
+        for i in 0..100 {
+            let key = format!("key-{:04}", i);
+            let val = format!("val-{:04}", i);
+            assert_eq!(db.get(&ReadOptions::default(), key.as_bytes()).unwrap(), val.as_bytes());
+        }
+        assert!(db.get(&ReadOptions::default(), b"key-0100").unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn plain_table() {
+        let opts = Options::default()
+            .map_db_options(|db| {
+                db.create_if_missing(true)
+                    // plain table is only supported with mmap reads
+                    .allow_mmap_reads(true)
+            })
+            .map_cf_options(|cf| {
+                cf.prefix_extractor_fixed(4).table_factory_plain(
+                    PlainTableOptions::default()
+                        .user_key_len(8)
+                        .bloom_bits_per_key(10)
+                        .hash_table_ratio(0.75)
+                        .index_sparseness(4),
+                )
+            });
+        fixed_size_round_trip(&opts);
+    }
+
+    fn cuckoo_options() -> Options {
+        Options::default()
+            .map_db_options(|db| {
+                db.create_if_missing(true)
+                    // cuckoo table is only supported with mmap reads
+                    .allow_mmap_reads(true)
+            })
+            .map_cf_options(|cf| {
+                cf.table_factory_cuckoo(
+                    CuckooTableOptions::default()
+                        .hash_table_ratio(0.9)
+                        .max_search_depth(100)
+                        .cuckoo_block_size(5),
+                )
+            })
+    }
+
+    #[test]
+    fn cuckoo_table() {
+        fixed_size_round_trip(&cuckoo_options());
+    }
+
+    #[test]
+    fn cuckoo_table_ingest() {
+        use sst_file_writer::SstFileWriter;
+
+        let sst_dir = ::tempdir::TempDir::new_in(".", "sst").unwrap();
+        let sst_path = sst_dir.path().join("cuckoo.sst");
+        let writer = SstFileWriter::builder().options(cuckoo_options()).build();
+        assert!(writer.open(&sst_path).is_ok());
+        for i in 0..100 {
+            let key = format!("key-{:04}", i);
+            let val = format!("val-{:04}", i);
+            assert!(writer.put(key.as_bytes(), val.as_bytes()).is_ok());
+        }
+        assert_eq!(writer.finish().unwrap().num_entries(), 100);
+
+        // CuckooTableBuilder runs no table properties collectors, so the file lacks
+        // the external sst file version property and RocksDB 5.8 refuses to ingest it
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(cuckoo_options(), &tmp_dir).unwrap();
+        let ret = db.ingest_external_file(&[&sst_path], &IngestExternalFileOptions::default());
+        assert!(ret.unwrap_err().is_corruption());
+        assert!(db.get(&ReadOptions::default(), b"key-0000").unwrap_err().is_not_found());
+    }
+}
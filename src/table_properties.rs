@@ -158,6 +158,28 @@ impl UserCollectedProperties {
         self.len() == 0
     }
 
+    /// Returns the value of a property, `None` if it's not collected
+    pub fn get(&self, key: &str) -> Option<&[u8]> {
+        let mut size = 0;
+        unsafe {
+            let val_ptr = ll::rocks_user_collected_props_at(
+                self.raw(),
+                key.as_bytes().as_ptr() as *const c_char,
+                key.len(),
+                &mut size,
+            );
+            if val_ptr.is_null() {
+                None
+            } else {
+                Some(slice::from_raw_parts(val_ptr as *const u8, size))
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
     pub fn iter(&self) -> UserCollectedPropertiesIter {
         UserCollectedPropertiesIter {
             raw: unsafe { ll::rocks_user_collected_props_iter_create(self.raw()) },
@@ -171,18 +193,9 @@ impl UserCollectedProperties {
 impl<'a> ops::Index<&'a str> for UserCollectedProperties {
     type Output = [u8];
     fn index(&self, index: &'a str) -> &[u8] {
-        let mut size = 0;
-        unsafe {
-            let val_ptr = ll::rocks_user_collected_props_at(
-                self.raw(),
-                index.as_bytes().as_ptr() as *const c_char,
-                index.len(),
-                &mut size,
-            );
-            if val_ptr.is_null() {
-                panic!("key not found {:?}", index);
-            }
-            slice::from_raw_parts(val_ptr as *const u8, size)
+        match self.get(index) {
+            Some(val) => val,
+            None => panic!("key not found {:?}", index),
         }
     }
 }
@@ -304,7 +317,13 @@ impl<'a> TableProperties<'a> {
     }
     /// If 0, key is variable length. Otherwise number of bytes for each key.
     pub fn fixed_key_len(&self) -> u64 {
-        unsafe { ll::rocks_table_props_get_format_version(self.raw) }
+        unsafe { ll::rocks_table_props_get_fixed_key_len(self.raw) }
+    }
+    /// the number of deletions in this table
+    ///
+    /// Rust: decoded from the `rocksdb.deleted.keys` user collected property
+    pub fn num_deletions(&self) -> u64 {
+        unsafe { ll::rocks_table_props_get_num_deletions(self.raw) }
     }
     /// ID of column family for this SST file, corresponding to the CF identified
     /// by column_family_name.
@@ -563,6 +582,7 @@ pub mod c {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::iter;
     use std::time;
 
@@ -666,4 +686,138 @@ mod tests {
         counters.dedup(); // assure files returned are all unique
         assert_eq!(counters.len(), 100);
     }
+
+    #[test]
+    fn user_collected_properties_get() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| cf.table_properties_collector_factory(Box::new(MyTblPropsCollectorFactory))),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"k1", b"v1").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let props = db.get_properties_of_tables_in_range(&db.default_column_family(), &[b"k0".as_ref()..b"k9".as_ref()])
+            .unwrap();
+        assert_eq!(props.len(), 1);
+        for (_, prop) in props.iter() {
+            let user_prop = prop.user_collected_properties();
+            assert_eq!(user_prop.get("sample_key"), Some(b"sample_value".as_ref()));
+            assert!(user_prop.contains_key("test.counter"));
+            assert_eq!(user_prop.get("missing_key"), None);
+            // builtin collector
+            assert!(user_prop.contains_key("rocksdb.deleted.keys"));
+
+            let map = user_prop.iter().map(|(k, v)| (k.to_owned(), v.to_vec())).collect::<HashMap<_, _>>();
+            assert_eq!(map["hello"], b"world");
+        }
+    }
+
+    #[derive(Default)]
+    pub struct MaxKeyCollector {
+        max_key: Vec<u8>,
+        num_puts: u64,
+    }
+
+    impl TablePropertiesCollector for MaxKeyCollector {
+        fn add_user_key(&mut self, key: &[u8], _value: &[u8], type_: EntryType, _seq: SequenceNumber, _size: u64) {
+            if type_ == EntryType::EntryPut {
+                self.num_puts += 1;
+            }
+            if key > &self.max_key[..] {
+                self.max_key = key.to_vec();
+            }
+        }
+
+        fn finish(&mut self, props: &mut UserCollectedProperties) {
+            props.insert("test.max_key", &self.max_key);
+            props.insert("test.num_puts", self.num_puts.to_string().as_bytes());
+        }
+
+        fn name(&self) -> &str {
+            "MaxKeyCollector\0"
+        }
+    }
+
+    pub struct MaxKeyCollectorFactory;
+
+    impl TablePropertiesCollectorFactory for MaxKeyCollectorFactory {
+        fn new_collector(&mut self, _context: Context) -> Box<TablePropertiesCollector> {
+            Box::new(MaxKeyCollector::default())
+        }
+
+        fn name(&self) -> &str {
+            "MaxKeyCollectorFactory\0"
+        }
+    }
+
+    #[test]
+    fn table_properties_collector_max_key() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true))
+                .map_cf_options(|cf| {
+                    cf.disable_auto_compactions(true)
+                        .table_properties_collector_factory(Box::new(MaxKeyCollectorFactory))
+                }),
+            &tmp_dir,
+        ).unwrap();
+
+        // event times, written out of order
+        for ts in &[1507000300u32, 1507000100, 1507000900, 1507000500] {
+            let key = format!("event-{}", ts);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"").is_ok());
+        }
+        assert!(db.delete(&WriteOptions::default(), b"event-1507000999").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let props = db.get_properties_of_tables_in_range(&db.default_column_family(), &[b"a".as_ref()..b"z".as_ref()])
+            .unwrap();
+        assert_eq!(props.len(), 1);
+        for (_, prop) in props.iter() {
+            assert!(prop.property_collectors_names().contains("MaxKeyCollectorFactory"));
+            let user_prop = prop.user_collected_properties();
+            assert_eq!(&user_prop["test.max_key"], b"event-1507000999");
+            assert_eq!(&user_prop["test.num_puts"], b"4");
+        }
+    }
+
+    #[test]
+    fn table_properties_numeric_fields() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = DB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true)),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..100 {
+            let key = format!("key-{:03}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"value").is_ok());
+        }
+        for i in 100..130 {
+            let key = format!("key-{:03}", i);
+            assert!(db.delete(&WriteOptions::default(), key.as_bytes()).is_ok());
+        }
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let props = db.get_properties_of_tables_in_range(&db.default_column_family(), &[b"a".as_ref()..b"z".as_ref()])
+            .unwrap();
+        assert_eq!(props.len(), 1);
+        for (_, prop) in props.iter() {
+            assert_eq!(prop.num_entries(), 130);
+            assert_eq!(prop.num_deletions(), 30);
+            assert!(prop.num_data_blocks() >= 1);
+            assert!(prop.data_size() > 0);
+            assert!(prop.index_size() > 0);
+            assert_eq!(prop.filter_size(), 0);
+            // internal keys carry an 8 byte sequence number and type
+            assert_eq!(prop.raw_key_size(), 130 * (7 + 8));
+            assert_eq!(prop.raw_value_size(), 100 * 5);
+            assert_eq!(prop.fixed_key_len(), 0);
+        }
+    }
 }
@@ -0,0 +1,179 @@
+//! A transaction on an `OptimisticTransactionDB` or a `TransactionDB`.
+//!
+//! Writes are buffered in the transaction until `commit()`, and reads see the
+//! transaction's own uncommitted writes.
+
+use std::ptr;
+use std::marker::PhantomData;
+
+use rocks_sys as ll;
+
+use error::Status;
+use options::ReadOptions;
+use to_raw::{FromRaw, ToRaw};
+use Result;
+
+/// A transaction started by `begin_transaction()`, borrowing the DB it was started on.
+///
+/// Dropping an uncommitted transaction discards all of its writes.
+pub struct Transaction<'a> {
+    raw: *mut ll::rocks_transaction_t,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Drop for Transaction<'a> {
+    fn drop(&mut self) {
+        unsafe { ll::rocks_transaction_destroy(self.raw) }
+    }
+}
+
+impl<'a> ToRaw<ll::rocks_transaction_t> for Transaction<'a> {
+    fn raw(&self) -> *mut ll::rocks_transaction_t {
+        self.raw
+    }
+}
+
+impl<'a> FromRaw<ll::rocks_transaction_t> for Transaction<'a> {
+    unsafe fn from_ll(raw: *mut ll::rocks_transaction_t) -> Transaction<'a> {
+        Transaction {
+            raw: raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> Transaction<'a> {
+    /// Sets the lock timeout in milliseconds for this transaction, overriding
+    /// `TransactionDBOptions::default_lock_timeout`.
+    ///
+    /// A negative value means no timeout. Has no effect on transactions of an
+    /// `OptimisticTransactionDB`, which don't take locks.
+    pub fn set_lock_timeout(&self, timeout: i64) {
+        unsafe { ll::rocks_transaction_set_lock_timeout(self.raw, timeout) }
+    }
+
+    /// Write all batched keys to the db atomically.
+    ///
+    /// Returns OK on success.
+    ///
+    /// May return any error status that could be returned by `DB::write()`.
+    ///
+    /// If this transaction was created by an `OptimisticTransactionDB`,
+    /// `Status::Busy()` may be returned if the transaction could not guarantee
+    /// that there are no write conflicts.  `Status::TryAgain()` may be returned
+    /// if the memtable history size is not large enough
+    /// (See max_write_buffer_number_to_maintain).
+    pub fn commit(&self) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_transaction_commit(self.raw, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// Discard all batched writes in this transaction.
+    pub fn rollback(&self) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_transaction_rollback(self.raw, &mut status);
+            Status::from_ll(status)
+        }
+    }
+
+    /// This function is similar to `DB::get()` except it will also read
+    /// pending changes in this transaction.
+    ///
+    /// If `read_options.snapshot` is not set, the current version of the key
+    /// will be read.  Calling `set_snapshot()` does not affect the version of the
+    /// data returned.
+    pub fn get(&self, options: &ReadOptions, key: &[u8]) -> Result<Vec<u8>> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        let mut value = Vec::new();
+        unsafe {
+            ll::rocks_transaction_get(
+                self.raw,
+                options.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                &mut value as *mut Vec<u8> as *mut _,
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| value)
+        }
+    }
+
+    /// Read this key and ensure that this transaction will only
+    /// be able to be committed if this key is not written outside this
+    /// transaction after it has first been read (or after the snapshot if a
+    /// snapshot is set in this transaction).
+    ///
+    /// If this transaction was created by an `OptimisticTransactionDB`,
+    /// `get_for_update()` will cause a `commit()` to fail with `Busy` if another
+    /// writer has written this key in the meantime.
+    ///
+    /// If this transaction was created by a `TransactionDB`, this function
+    /// locks the key, and returns a `TimedOut` status if the lock could not be
+    /// acquired within the lock timeout.
+    pub fn get_for_update(&self, options: &ReadOptions, key: &[u8]) -> Result<Vec<u8>> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        let mut value = Vec::new();
+        unsafe {
+            ll::rocks_transaction_get_for_update(
+                self.raw,
+                options.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                &mut value as *mut Vec<u8> as *mut _,
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| value)
+        }
+    }
+
+    /// Put, Merge, and Delete behave similarly to the corresponding
+    /// functions in `WriteBatch`, but will also do conflict checking on the
+    /// keys being written.
+    ///
+    /// If this transaction was created on an `OptimisticTransactionDB`, these
+    /// functions should always return `Ok`.
+    ///
+    /// If this transaction was created on a `TransactionDB`, a `TimedOut`
+    /// status is returned if the key could not be locked.
+    pub fn put(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_transaction_put(
+                self.raw,
+                key.as_ptr() as _,
+                key.len(),
+                value.as_ptr() as _,
+                value.len(),
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+
+    pub fn merge(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_transaction_merge(
+                self.raw,
+                key.as_ptr() as _,
+                key.len(),
+                value.as_ptr() as _,
+                value.len(),
+                &mut status,
+            );
+            Status::from_ll(status)
+        }
+    }
+
+    pub fn delete(&self, key: &[u8]) -> Result<()> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            ll::rocks_transaction_delete(self.raw, key.as_ptr() as _, key.len(), &mut status);
+            Status::from_ll(status)
+        }
+    }
+}
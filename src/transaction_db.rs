@@ -0,0 +1,211 @@
+//! A DB with pessimistic concurrency control: transactions lock the keys they
+//! read for update or write, until they commit or roll back.
+
+use std::ops;
+use std::path::Path;
+use std::ptr;
+
+use rocks_sys as ll;
+
+use db::DBRef;
+use error::Status;
+use options::{Options, WriteOptions};
+use to_raw::{FromRaw, ToRaw};
+use transaction::Transaction;
+use Result;
+
+/// Options for `TransactionDB`
+pub struct TransactionDBOptions {
+    raw: *mut ll::rocks_transactiondb_options_t,
+}
+
+impl ToRaw<ll::rocks_transactiondb_options_t> for TransactionDBOptions {
+    fn raw(&self) -> *mut ll::rocks_transactiondb_options_t {
+        self.raw
+    }
+}
+
+impl Default for TransactionDBOptions {
+    fn default() -> Self {
+        TransactionDBOptions { raw: unsafe { ll::rocks_transactiondb_options_create() } }
+    }
+}
+
+impl Drop for TransactionDBOptions {
+    fn drop(&mut self) {
+        unsafe {
+            ll::rocks_transactiondb_options_destroy(self.raw);
+        }
+    }
+}
+
+impl TransactionDBOptions {
+    /// Specifies the maximum number of keys that can be locked at the same time
+    /// per column family.
+    ///
+    /// If the number of locked keys is greater than `max_num_locks`, transaction
+    /// writes (or `get_for_update`) will return an error.
+    ///
+    /// If this value is not positive, no limit will be enforced.
+    pub fn max_num_locks(self, val: i64) -> Self {
+        unsafe {
+            ll::rocks_transactiondb_options_set_max_num_locks(self.raw, val);
+        }
+        self
+    }
+
+    /// Increasing this value will increase the concurrency by dividing the lock
+    /// table (per column family) into more sub-tables, each with their own
+    /// separate mutex.
+    pub fn num_stripes(self, val: usize) -> Self {
+        unsafe {
+            ll::rocks_transactiondb_options_set_num_stripes(self.raw, val);
+        }
+        self
+    }
+
+    /// If positive, specifies the default wait timeout in milliseconds when
+    /// a transaction attempts to lock a key if not specified by
+    /// `Transaction::set_lock_timeout()`
+    ///
+    /// If 0, no waiting is done if a lock cannot instantly be acquired.
+    /// If negative, there is no timeout. Not using a timeout is not recommended
+    /// as it can lead to deadlocks.
+    ///
+    /// Default: 1000
+    pub fn transaction_lock_timeout(self, val: i64) -> Self {
+        unsafe {
+            ll::rocks_transactiondb_options_set_transaction_lock_timeout(self.raw, val);
+        }
+        self
+    }
+
+    /// If positive, specifies the wait timeout in milliseconds when writing a key
+    /// OUTSIDE of a transaction (ie by calling `DB::put()`, `merge()`, `delete()`
+    /// or `write()` directly).
+    ///
+    /// If 0, no waiting is done if a lock cannot instantly be acquired.
+    /// If negative, there is no timeout and will block indefinitely when acquiring
+    /// a lock.
+    ///
+    /// Default: 1000
+    pub fn default_lock_timeout(self, val: i64) -> Self {
+        unsafe {
+            ll::rocks_transactiondb_options_set_default_lock_timeout(self.raw, val);
+        }
+        self
+    }
+}
+
+/// A DB supporting pessimistic transactions.
+///
+/// Derefs to `DBRef`, plain writes made through it also take the key locks
+/// and wait for conflicting transactions, see
+/// `TransactionDBOptions::default_lock_timeout`.
+pub struct TransactionDB {
+    raw: *mut ll::rocks_transactiondb_t,
+    base: Box<DBRef<'static>>,
+}
+
+unsafe impl Sync for TransactionDB {}
+unsafe impl Send for TransactionDB {}
+
+impl Drop for TransactionDB {
+    fn drop(&mut self) {
+        unsafe {
+            // base DB is the TransactionDB itself, only free the wrapper
+            ll::rocks_transactiondb_close_base_db(self.base.raw());
+            ll::rocks_transactiondb_close(self.raw);
+        }
+    }
+}
+
+impl ops::Deref for TransactionDB {
+    type Target = DBRef<'static>;
+
+    fn deref(&self) -> &DBRef<'static> {
+        &self.base
+    }
+}
+
+impl TransactionDB {
+    /// Open a TransactionDB similar to `DB::open()`.
+    pub fn open<T: AsRef<Options>, P: AsRef<Path>>(
+        options: T,
+        txn_db_options: &TransactionDBOptions,
+        name: P,
+    ) -> Result<TransactionDB> {
+        let name = name.as_ref().to_str().expect("valid path");
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        unsafe {
+            let raw = ll::rocks_transactiondb_open(
+                options.as_ref().raw(),
+                txn_db_options.raw(),
+                name.as_bytes().as_ptr() as *const _,
+                name.len(),
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| {
+                TransactionDB {
+                    raw: raw,
+                    base: Box::new(DBRef::from_ll(ll::rocks_transactiondb_get_base_db(raw))),
+                }
+            })
+        }
+    }
+
+    /// Starts a new Transaction.
+    ///
+    /// Keys are locked as they are read with `get_for_update()` or written,
+    /// and unlocked on `commit()` or `rollback()`.
+    pub fn begin_transaction(&self, write_options: &WriteOptions) -> Transaction {
+        unsafe {
+            Transaction::from_ll(ll::rocks_transactiondb_begin_transaction(
+                self.raw,
+                write_options.raw(),
+            ))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+    use error::{Code, SubCode};
+
+    #[test]
+    fn transaction_lock_timeout() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let db = TransactionDB::open(
+            Options::default().map_db_options(|db| db.create_if_missing(true)),
+            &TransactionDBOptions::default().transaction_lock_timeout(5000),
+            &tmp_dir,
+        ).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"counter", b"0").is_ok());
+
+        let txn1 = db.begin_transaction(&WriteOptions::default());
+        let txn2 = db.begin_transaction(&WriteOptions::default());
+        txn2.set_lock_timeout(10);
+
+        assert_eq!(txn1.get_for_update(&ReadOptions::default(), b"counter").unwrap(), b"0");
+
+        // txn1 holds the lock
+        let err = txn2.get_for_update(&ReadOptions::default(), b"counter").unwrap_err();
+        assert_eq!(err.code(), Code::TimedOut);
+        assert_eq!(err.subcode(), SubCode::LockTimeout);
+        assert_eq!(txn2.put(b"counter", b"2").unwrap_err().code(), Code::TimedOut);
+
+        assert!(txn1.put(b"counter", b"1").is_ok());
+        assert!(txn1.commit().is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"counter").unwrap(), b"1".as_ref());
+
+        // lock is released on commit
+        assert_eq!(txn2.get_for_update(&ReadOptions::default(), b"counter").unwrap(), b"1");
+        assert!(txn2.delete(b"counter").is_ok());
+        assert!(txn2.rollback().is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"counter").unwrap(), b"1".as_ref());
+    }
+}
@@ -150,6 +150,44 @@ impl Iterator for TransactionLogIterator {
     }
 }
 
+/// Rust-style WAL tailing iterator, made by `DB::tail_wal_from()`
+///
+/// Yields `(SequenceNumber, WriteBatch)` pairs, stops when no more records are
+/// available or after yielding the first error.
+#[derive(Debug, Default)]
+pub struct WalTail {
+    inner: Option<TransactionLogIterator>,
+}
+
+impl WalTail {
+    pub fn new(inner: TransactionLogIterator) -> WalTail {
+        WalTail { inner: Some(inner) }
+    }
+}
+
+impl Iterator for WalTail {
+    type Item = Result<(SequenceNumber, WriteBatch)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = match self.inner {
+            Some(ref mut it) => {
+                if let Err(e) = it.status() {
+                    Some(Err(e))
+                } else if it.is_valid() {
+                    let BatchResult { sequence, write_batch } = it.get_batch();
+                    it.move_next();
+                    return Some(Ok((sequence, write_batch)));
+                } else {
+                    None
+                }
+            }
+            None => return None,
+        };
+        self.inner = None;
+        ret
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::rocksdb::*;
@@ -212,4 +250,131 @@ mod tests {
             assert!(batch.sequence.0 > 20 - 3);
         }
     }
+
+    #[test]
+    fn drain_updates_since() {
+        let tmp_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
+        let db = DB::open(
+            Options::default().map_db_options(|db| {
+                db.create_if_missing(true)
+                    .wal_ttl_seconds(1000000)
+                    .wal_size_limit_mb(1024)
+            }),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..50 {
+            let mut batch = WriteBatch::default();
+            batch
+                .put(format!("K{}", i).as_bytes(), b"v")
+                .put(format!("M{}", i).as_bytes(), b"v");
+            if i % 2 == 0 {
+                batch.put(format!("N{}", i).as_bytes(), b"v");
+            }
+            assert!(db.write(WriteOptions::default_instance(), batch).is_ok());
+        }
+        let latest = db.get_latest_sequence_number().0;
+
+        let mut next = db.get_updates_since(0.into()).unwrap().get_batch().sequence;
+        let mut total = 0;
+        loop {
+            let (batches, resume) = db.drain_updates_since(next, 7).unwrap();
+            if batches.is_empty() {
+                assert_eq!(resume, next);
+                break;
+            }
+            assert!(batches.len() <= 7);
+            for (seq, batch) in batches {
+                // contiguous: each batch starts right where the previous one ended
+                assert_eq!(seq, next);
+                next = (seq.0 + batch.count() as u64).into();
+                total += 1;
+            }
+            assert_eq!(resume, next);
+        }
+        assert_eq!(total, 50);
+        assert_eq!(next.0, latest + 1);
+    }
+
+    #[test]
+    fn replay_updates_since() {
+        let tmp_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
+        let replica_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
+        let opts = Options::default().map_db_options(|db| {
+            db.create_if_missing(true)
+                .wal_ttl_seconds(1000000)
+                .wal_size_limit_mb(1024)
+        });
+        let db = DB::open(&opts, &tmp_dir).unwrap();
+        let replica = DB::open(&opts, &replica_dir).unwrap();
+
+        // written before the starting point, not replayed
+        assert!(db.put(&WriteOptions::default(), b"skipped", b"v").is_ok());
+        let since = (db.get_latest_sequence_number().0 + 1).into();
+
+        for i in 0..20 {
+            let val = format!("v{}", i);
+            assert!(db.put(&WriteOptions::default(), format!("k{}", i).as_bytes(), val.as_bytes()).is_ok());
+        }
+        let mut batch = WriteBatch::default();
+        batch.delete(b"k3").put(b"k4", b"overwritten");
+        assert!(db.write(WriteOptions::default_instance(), batch).is_ok());
+        assert!(db.delete(&WriteOptions::default(), b"skipped").is_ok());
+
+        let mut replayed = 0;
+        for batch in db.get_updates_since(since).unwrap() {
+            assert!(batch.sequence.0 >= since.0);
+            assert!(replica.write(WriteOptions::default_instance(), batch.write_batch).is_ok());
+            replayed += 1;
+        }
+        assert_eq!(replayed, 22);
+
+        let contents = |db: &DB| {
+            db.iter(&ReadOptions::default())
+                .map(|(k, v)| (k.to_vec(), v.to_vec()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(contents(&db), contents(&replica));
+        assert_eq!(contents(&replica).len(), 19);
+    }
+
+    #[test]
+    fn tail_wal_from() {
+        let tmp_dir = ::tempdir::TempDir::new_in("", "rocks").unwrap();
+        let db = DB::open(
+            Options::default().map_db_options(|db| {
+                db.create_if_missing(true)
+                    .wal_ttl_seconds(1000000)
+                    .wal_size_limit_mb(1024)
+            }),
+            &tmp_dir,
+        ).unwrap();
+
+        for i in 0..10 {
+            assert!(db.put(&WriteOptions::default(), format!("k{}", i).as_bytes(), b"v").is_ok());
+        }
+        let since = (db.get_latest_sequence_number().0 + 1).into();
+        assert_eq!(db.tail_wal_from(since).unwrap().count(), 0);
+
+        for i in 0..20 {
+            let mut batch = WriteBatch::default();
+            batch
+                .put(format!("K{}", i).as_bytes(), b"v")
+                .delete(format!("k{}", i).as_bytes());
+            assert!(db.write(WriteOptions::default_instance(), batch).is_ok());
+        }
+
+        let updates = db.tail_wal_from(since)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(updates.len(), 20);
+        let mut next = since;
+        for (seq, batch) in updates {
+            assert_eq!(seq, next);
+            assert_eq!(batch.count(), 2);
+            next = (seq.0 + 2).into();
+        }
+        assert_eq!(next.0, db.get_latest_sequence_number().0 + 1);
+    }
 }
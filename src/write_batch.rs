@@ -22,9 +22,23 @@ use std::os::raw::{c_uchar, c_void};
 use rocks_sys as ll;
 
 use db::ColumnFamilyHandle;
+use error::Status;
 use to_raw::{FromRaw, ToRaw};
 use super::Result;
 
+/// WriteBatch header has an 8-byte sequence number followed by a 4-byte count.
+const HEADER_SIZE: usize = 12;
+
+/// State of a `WriteBatch` at a marked point
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SavePoint {
+    /// size of rep_
+    pub size: usize,
+    /// count of elements in rep_
+    pub count: usize,
+    pub content_flags: u32,
+}
+
 /// `WriteBatch` holds a collection of updates to apply atomically to a DB.
 pub struct WriteBatch {
     raw: *mut ll::rocks_writebatch_t,
@@ -77,6 +91,17 @@ impl WriteBatch {
         WriteBatch { raw: unsafe { ll::rocks_writebatch_create_with_reserved_bytes(reserved_bytes) } }
     }
 
+    /// Reconstructs a batch from its serialized version, as returned by `get_data()`.
+    ///
+    /// Rust: only the header size is checked here, malformed contents are reported
+    /// when the batch is written or iterated.
+    pub fn from_data(data: &[u8]) -> Result<WriteBatch> {
+        if data.len() < HEADER_SIZE {
+            return Err(Status::with_message("malformed WriteBatch (too small)"));
+        }
+        Ok(WriteBatch { raw: unsafe { ll::rocks_writebatch_create_from(data.as_ptr() as _, data.len()) } })
+    }
+
     /// Clear all updates buffered in this batch.
     pub fn clear(&mut self) {
         unsafe {
@@ -142,6 +167,10 @@ impl WriteBatch {
     }
 
     /// WriteBatch implementation of DB::SingleDelete().  See db.h.
+    ///
+    /// Requires that the key exists and was written by exactly one `put()` since
+    /// the previous single delete, and was never deleted by `delete()` or written
+    /// by `merge()`.  Otherwise the result is undefined.
     pub fn single_delete(&mut self, key: &[u8]) -> &mut Self {
         unsafe {
             ll::rocks_writebatch_single_delete(self.raw, key.as_ptr() as _, key.len());
@@ -284,6 +313,31 @@ impl WriteBatch {
         }
     }
 
+    /// Marks this point in the WriteBatch as the last record to
+    /// be inserted into the WAL, provided the WAL is enabled.
+    pub fn mark_wal_termination_point(&mut self) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_mark_wal_termination_point(self.raw);
+        }
+        self
+    }
+
+    /// Returns the batch state recorded by `mark_wal_termination_point()`,
+    /// all zeros if it was never marked.
+    pub fn get_wal_termination_point(&self) -> SavePoint {
+        let mut size = 0;
+        let mut count = 0;
+        let mut content_flags = 0;
+        unsafe {
+            ll::rocks_writebatch_get_wal_termination_point(self.raw, &mut size, &mut count, &mut content_flags);
+        }
+        SavePoint {
+            size: size,
+            count: count as usize,
+            content_flags: content_flags,
+        }
+    }
+
     /// Support for iterating over the contents of a batch.
     pub fn iterate<H: WriteBatchHandler>(&self, handler: &mut H) -> Result<()> {
         let mut status = ptr::null_mut();
@@ -304,6 +358,11 @@ impl WriteBatch {
         }
     }
 
+    /// Retrieve data size of the batch.
+    pub fn get_data_size(&self) -> usize {
+        unsafe { ll::rocks_writebatch_get_data_size(self.raw) }
+    }
+
     /// Returns the number of updates in the batch
     pub fn count(&self) -> usize {
         unsafe { ll::rocks_writebatch_count(self.raw) as usize }
@@ -374,6 +433,9 @@ pub trait WriteBatchHandler {
     /// Continue is called by WriteBatch::Iterate. If it returns false,
     /// iteration is halted. Otherwise, it continues iterating. The default
     /// implementation always returns true.
+    ///
+    /// A halted `iterate` returns a `Corruption` status, as fewer updates
+    /// than the batch count were visited.
     fn will_continue(&mut self) -> bool {
         true
     }
@@ -621,4 +683,296 @@ mod tests {
         assert_eq!(db.get(&ReadOptions::default(), b"name").unwrap().as_ref(), b"BH1XUW");
         assert_eq!(db.get(&ReadOptions::default(), b"site").unwrap().as_ref(), b"github");
     }
+
+    #[test]
+    fn write_batch_single_delete() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let db = DB::open(opt, &tmp_dir).unwrap();
+        let cf = db.create_column_family(&ColumnFamilyOptions::default(), "cf1").unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"name", b"rocksdb").is_ok());
+        assert!(db.put_cf(&WriteOptions::default(), &cf, b"site", b"github").is_ok());
+        assert!(db.flush(&FlushOptions::default().wait(true)).is_ok());
+
+        let mut batch = WriteBatch::new();
+        batch.single_delete(b"name").single_delete_cf(&cf, b"site");
+        assert!(batch.has_single_delete());
+        assert_eq!(batch.count(), 2);
+
+        assert!(db.write(&WriteOptions::default(), batch).is_ok());
+
+        assert!(db.get(&ReadOptions::default(), b"name").unwrap_err().is_not_found());
+        assert!(db.get_cf(&ReadOptions::default(), &cf, b"site").unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn write_batch_delete_range() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let db = DB::open(opt, &tmp_dir).unwrap();
+
+        for i in 0..5 {
+            let key = format!("k{}", i);
+            assert!(db.put(&WriteOptions::default(), key.as_bytes(), b"old").is_ok());
+        }
+
+        let mut batch = WriteBatch::new();
+        for i in 5..10 {
+            let key = format!("k{}", i);
+            batch.put(key.as_bytes(), b"new");
+        }
+        // covers both existing keys and keys put earlier in this batch
+        batch.delete_range(b"k3", b"k7");
+        // put after the range delete in the same batch survives
+        batch.put(b"k4", b"newer");
+        assert!(batch.has_delete_range());
+
+        assert!(db.write(&WriteOptions::default(), batch).is_ok());
+
+        let kvs = db.iter(&ReadOptions::default())
+            .map(|(k, v)| (String::from_utf8_lossy(&k).to_string(), String::from_utf8_lossy(&v).to_string()))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            ("k0", "old"),
+            ("k1", "old"),
+            ("k2", "old"),
+            ("k4", "newer"),
+            ("k7", "new"),
+            ("k8", "new"),
+            ("k9", "new"),
+        ].into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(kvs, expected);
+    }
+
+    #[test]
+    fn write_batch_wal_termination_point() {
+        let mut batch = WriteBatch::new();
+        assert_eq!(batch.get_wal_termination_point(), SavePoint::default());
+
+        batch.put(b"k1", b"v1").put(b"k2", b"v2").delete(b"k0");
+        let size = batch.get_data().len();
+        batch.mark_wal_termination_point();
+        batch.put(b"k3", b"v3").merge(b"k4", b"v4");
+
+        let point = batch.get_wal_termination_point();
+        assert_eq!(point.count, 3);
+        assert_eq!(point.size, size);
+        assert_eq!(batch.count(), 5);
+        assert!(batch.get_data().len() > point.size);
+    }
+
+    #[test]
+    fn write_batch_merge() {
+        use env::Logger;
+        use merge_operator::AssociativeMergeOperator;
+
+        struct AddOperator;
+
+        impl AssociativeMergeOperator for AddOperator {
+            fn merge(
+                &self,
+                _key: &[u8],
+                existing_value: Option<&[u8]>,
+                value: &[u8],
+                _logger: &Logger,
+            ) -> Option<Vec<u8>> {
+                let existing = existing_value
+                    .and_then(|v| String::from_utf8_lossy(v).parse::<u64>().ok())
+                    .unwrap_or(0);
+                String::from_utf8_lossy(value)
+                    .parse::<u64>()
+                    .ok()
+                    .map(|operand| (existing + operand).to_string().into_bytes())
+            }
+        }
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default()
+            .map_db_options(|db| db.create_if_missing(true))
+            .map_cf_options(|cf| cf.associative_merge_operator(Box::new(AddOperator)));
+        let db = DB::open(opt, &tmp_dir).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"counter", b"10").is_ok());
+
+        let mut batch = WriteBatch::new();
+        batch
+            .merge(b"counter", b"1")
+            .put(b"name", b"rocksdb")
+            .merge(b"counter", b"2")
+            .merge(b"fresh", b"5");
+        assert!(batch.has_merge());
+        assert_eq!(batch.count(), 4);
+
+        assert!(db.write(&WriteOptions::default(), batch).is_ok());
+
+        assert_eq!(db.get(&ReadOptions::default(), b"counter").unwrap().as_ref(), b"13");
+        assert_eq!(db.get(&ReadOptions::default(), b"fresh").unwrap().as_ref(), b"5");
+        assert_eq!(db.get(&ReadOptions::default(), b"name").unwrap().as_ref(), b"rocksdb");
+    }
+
+    #[test]
+    fn write_batch_save_point() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let db = DB::open(opt, &tmp_dir).unwrap();
+
+        let mut batch = WriteBatch::new();
+        // no save point yet
+        assert!(batch.rollback_to_save_point().unwrap_err().is_not_found());
+
+        batch.put(b"k1", b"v1").put(b"k2", b"v2");
+        batch.set_save_point();
+        batch.put(b"k3", b"v3").delete(b"k1");
+        batch.set_save_point();
+        batch.put(b"k4", b"v4");
+        assert_eq!(batch.count(), 5);
+
+        // save points are a stack
+        assert!(batch.rollback_to_save_point().is_ok());
+        assert_eq!(batch.count(), 4);
+        assert!(batch.rollback_to_save_point().is_ok());
+        assert_eq!(batch.count(), 2);
+        assert!(batch.rollback_to_save_point().is_err());
+
+        assert!(db.write(&WriteOptions::default(), batch).is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"k1").unwrap().as_ref(), b"v1");
+        assert_eq!(db.get(&ReadOptions::default(), b"k2").unwrap().as_ref(), b"v2");
+        assert!(db.get(&ReadOptions::default(), b"k3").unwrap_err().is_not_found());
+        assert!(db.get(&ReadOptions::default(), b"k4").unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn write_batch_from_data() {
+        let origin_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let replica_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let origin = DB::open(&opt, &origin_dir).unwrap();
+        let replica = DB::open(&opt, &replica_dir).unwrap();
+
+        let mut batch = WriteBatch::new();
+        batch
+            .put(b"k1", b"v1")
+            .put(b"k2", b"v2")
+            .put(b"k3", b"v3")
+            .delete(b"k2")
+            .single_delete(b"k3")
+            .delete_range(b"k5", b"k7")
+            .put(b"k6", b"v6")
+            .put_log_data(b"log data");
+
+        let data = batch.get_data().to_vec();
+        let restored = WriteBatch::from_data(&data).unwrap();
+        assert_eq!(restored.count(), batch.count());
+        assert_eq!(restored.get_data(), &data[..]);
+        assert!(restored.has_delete_range());
+
+        assert!(origin.write(&WriteOptions::default(), batch).is_ok());
+        assert!(replica.write(&WriteOptions::default(), restored).is_ok());
+
+        let ropts = ReadOptions::default();
+        let origin_kvs = origin.iter(&ropts).map(|(k, v)| (k.to_vec(), v.to_vec())).collect::<Vec<_>>();
+        let replica_kvs = replica.iter(&ropts).map(|(k, v)| (k.to_vec(), v.to_vec())).collect::<Vec<_>>();
+        assert_eq!(origin_kvs.len(), 2);
+        assert_eq!(origin_kvs, replica_kvs);
+
+        assert!(WriteBatch::from_data(b"short").is_err());
+    }
+
+    #[test]
+    fn write_batch_handler_count_by_type() {
+        #[derive(Default)]
+        struct OpCounter {
+            puts: usize,
+            deletes: usize,
+            merges: usize,
+            delete_ranges: usize,
+            limit: Option<usize>,
+        }
+
+        impl OpCounter {
+            fn total(&self) -> usize {
+                self.puts + self.deletes + self.merges + self.delete_ranges
+            }
+        }
+
+        impl WriteBatchHandler for OpCounter {
+            fn put_cf(&mut self, _: u32, _: &[u8], _: &[u8]) {
+                self.puts += 1;
+            }
+            fn delete_cf(&mut self, _: u32, _: &[u8]) {
+                self.deletes += 1;
+            }
+            fn single_delete_cf(&mut self, _: u32, _: &[u8]) {
+                self.deletes += 1;
+            }
+            fn delete_range_cf(&mut self, _: u32, _: &[u8], _: &[u8]) {
+                self.delete_ranges += 1;
+            }
+            fn merge_cf(&mut self, _: u32, _: &[u8], _: &[u8]) {
+                self.merges += 1;
+            }
+            fn will_continue(&mut self) -> bool {
+                self.limit.map_or(true, |limit| self.total() < limit)
+            }
+        }
+
+        let mut batch = WriteBatch::new();
+        batch
+            .put(b"k1", b"v1")
+            .merge(b"k1", b"v2")
+            .delete(b"k2")
+            .put(b"k3", b"v3")
+            .delete_range(b"k4", b"k6")
+            .single_delete(b"k3")
+            .merge(b"k7", b"v7")
+            .put_log_data(b"not counted");
+
+        let mut counter = OpCounter::default();
+        assert!(batch.iterate(&mut counter).is_ok());
+        assert_eq!(counter.puts, 2);
+        assert_eq!(counter.deletes, 2);
+        assert_eq!(counter.merges, 2);
+        assert_eq!(counter.delete_ranges, 1);
+        assert_eq!(counter.total(), batch.count());
+
+        // halts early once `will_continue` returns false
+        let mut counter = OpCounter {
+            limit: Some(3),
+            ..Default::default()
+        };
+        // RocksDB 5.8 reports the halted iteration as a count mismatch
+        let err = batch.iterate(&mut counter).unwrap_err();
+        assert!(err.is_corruption(), "got {:?}", err);
+        assert_eq!(counter.total(), 3);
+    }
+
+    #[test]
+    fn write_batch_count_and_size() {
+        let mut batch = WriteBatch::new();
+        let header_size = batch.get_data_size();
+        assert_eq!(batch.count(), 0);
+
+        for i in 0..10 {
+            batch.put(format!("k{}", i).as_bytes(), b"value");
+        }
+        for i in 0..4 {
+            batch.delete(format!("k{}", i).as_bytes());
+        }
+        assert_eq!(batch.count(), 10 + 4);
+        assert!(batch.get_data_size() > header_size);
+        assert_eq!(batch.get_data_size(), batch.get_data().len());
+
+        // log data takes space but is not counted
+        let size = batch.get_data_size();
+        batch.put_log_data(b"blob");
+        assert_eq!(batch.count(), 14);
+        assert!(batch.get_data_size() > size);
+
+        batch.clear();
+        assert_eq!(batch.count(), 0);
+        assert_eq!(batch.get_data_size(), header_size);
+    }
 }
@@ -0,0 +1,298 @@
+//! A `WriteBatch` with a binary searchable index built for all the keys inserted.
+//!
+//! Reads can then be served from the batch, on its own or overlaid on top of
+//! the DB, before the batch is written.
+
+use std::ptr;
+
+use rocks_sys as ll;
+
+use db::{ColumnFamilyHandle, DBRef};
+use error::Status;
+use options::{DBOptions, ReadOptions};
+use slice::PinnableSlice;
+use to_raw::{FromRaw, ToRaw};
+use write_batch::WriteBatch;
+use Result;
+
+/// A `WriteBatchWithIndex` with a binary searchable index built for all the keys
+/// inserted.
+///
+/// In `put()`, `merge()` or `delete()`, the same function of the wrapped will be
+/// called. At the same time, indexes will be built.
+pub struct WriteBatchWithIndex {
+    raw: *mut ll::rocks_writebatch_with_index_t,
+}
+
+impl Drop for WriteBatchWithIndex {
+    fn drop(&mut self) {
+        unsafe { ll::rocks_writebatch_with_index_destroy(self.raw) }
+    }
+}
+
+impl Default for WriteBatchWithIndex {
+    fn default() -> Self {
+        WriteBatchWithIndex::new()
+    }
+}
+
+impl WriteBatchWithIndex {
+    pub fn new() -> WriteBatchWithIndex {
+        WriteBatchWithIndex::with_options(0, false)
+    }
+
+    /// `reserved_bytes`: reserved bytes in underlying WriteBatch
+    ///
+    /// `overwrite_key`: if true, overwrite the key in the index when inserting
+    /// the same key as previously, so iterator will never show two entries with
+    /// the same key.
+    pub fn with_options(reserved_bytes: usize, overwrite_key: bool) -> WriteBatchWithIndex {
+        WriteBatchWithIndex {
+            raw: unsafe { ll::rocks_writebatch_with_index_create(reserved_bytes, overwrite_key as u8) },
+        }
+    }
+
+    pub fn put(&mut self, key: &[u8], value: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_put(
+                self.raw,
+                key.as_ptr() as _,
+                key.len(),
+                value.as_ptr() as _,
+                value.len(),
+            );
+        }
+        self
+    }
+
+    pub fn put_cf(&mut self, column_family: &ColumnFamilyHandle, key: &[u8], value: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_put_cf(
+                self.raw,
+                column_family.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                value.as_ptr() as _,
+                value.len(),
+            );
+        }
+        self
+    }
+
+    pub fn merge(&mut self, key: &[u8], value: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_merge(
+                self.raw,
+                key.as_ptr() as _,
+                key.len(),
+                value.as_ptr() as _,
+                value.len(),
+            );
+        }
+        self
+    }
+
+    pub fn merge_cf(&mut self, column_family: &ColumnFamilyHandle, key: &[u8], value: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_merge_cf(
+                self.raw,
+                column_family.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                value.as_ptr() as _,
+                value.len(),
+            );
+        }
+        self
+    }
+
+    pub fn delete(&mut self, key: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_delete(self.raw, key.as_ptr() as _, key.len());
+        }
+        self
+    }
+
+    pub fn delete_cf(&mut self, column_family: &ColumnFamilyHandle, key: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_delete_cf(self.raw, column_family.raw(), key.as_ptr() as _, key.len());
+        }
+        self
+    }
+
+    pub fn single_delete(&mut self, key: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_single_delete(self.raw, key.as_ptr() as _, key.len());
+        }
+        self
+    }
+
+    pub fn single_delete_cf(&mut self, column_family: &ColumnFamilyHandle, key: &[u8]) -> &mut Self {
+        unsafe {
+            ll::rocks_writebatch_with_index_single_delete_cf(
+                self.raw,
+                column_family.raw(),
+                key.as_ptr() as _,
+                key.len(),
+            );
+        }
+        self
+    }
+
+    /// Clear all updates buffered in this batch.
+    pub fn clear(&mut self) {
+        unsafe {
+            ll::rocks_writebatch_with_index_clear(self.raw);
+        }
+    }
+
+    /// Returns the number of updates in the batch
+    pub fn count(&self) -> usize {
+        unsafe { ll::rocks_writebatch_with_index_count(self.raw) as usize }
+    }
+
+    /// Returns the underlying WriteBatch.
+    ///
+    /// Rust: returns a copy, which can be passed to `DB::write()`.
+    pub fn get_write_batch(&self) -> WriteBatch {
+        unsafe { WriteBatch::from_ll(ll::rocks_writebatch_with_index_get_writebatch(self.raw)) }
+    }
+
+    /// Similar to `DB::get()` but will only read the key from this batch.
+    /// If the batch does not have enough data to resolve Merge operations,
+    /// MergeInProgress status may be returned.
+    pub fn get_from_batch(&self, options: &DBOptions, key: &[u8]) -> Result<Vec<u8>> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        let mut value = Vec::new();
+        unsafe {
+            ll::rocks_writebatch_with_index_get_from_batch(
+                self.raw,
+                options.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                &mut value as *mut Vec<u8> as *mut _,
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| value)
+        }
+    }
+
+    pub fn get_from_batch_cf(
+        &self,
+        column_family: &ColumnFamilyHandle,
+        options: &DBOptions,
+        key: &[u8],
+    ) -> Result<Vec<u8>> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        let mut value = Vec::new();
+        unsafe {
+            ll::rocks_writebatch_with_index_get_from_batch_cf(
+                self.raw,
+                column_family.raw(),
+                options.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                &mut value as *mut Vec<u8> as *mut _,
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| value)
+        }
+    }
+
+    /// Similar to `DB::get()` but will also read writes from this batch.
+    ///
+    /// This function will query both this batch and the DB and then merge
+    /// the results using the DB's merge operator (if the batch contains any
+    /// merge requests).
+    ///
+    /// Setting `ReadOptions::snapshot()` will affect what is read from the DB
+    /// but will NOT change which keys are read from the batch (the keys in
+    /// this batch do not yet belong to any snapshot and will be fetched
+    /// regardless).
+    pub fn get_from_batch_and_db(&self, db: &DBRef, options: &ReadOptions, key: &[u8]) -> Result<PinnableSlice> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        let pinnable_val = PinnableSlice::new();
+        unsafe {
+            ll::rocks_writebatch_with_index_get_from_batch_and_db(
+                self.raw,
+                db.raw(),
+                options.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                pinnable_val.raw(),
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| pinnable_val)
+        }
+    }
+
+    pub fn get_from_batch_and_db_cf(
+        &self,
+        db: &DBRef,
+        options: &ReadOptions,
+        column_family: &ColumnFamilyHandle,
+        key: &[u8],
+    ) -> Result<PinnableSlice> {
+        let mut status = ptr::null_mut::<ll::rocks_status_t>();
+        let pinnable_val = PinnableSlice::new();
+        unsafe {
+            ll::rocks_writebatch_with_index_get_from_batch_and_db_cf(
+                self.raw,
+                db.raw(),
+                options.raw(),
+                column_family.raw(),
+                key.as_ptr() as _,
+                key.len(),
+                pinnable_val.raw(),
+                &mut status,
+            );
+            Status::from_ll(status).map(|_| pinnable_val)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rocksdb::*;
+
+    #[test]
+    fn write_batch_with_index() {
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let opt = Options::default().map_db_options(|db| db.create_if_missing(true));
+        let db = DB::open(&opt, &tmp_dir).unwrap();
+
+        assert!(db.put(&WriteOptions::default(), b"k1", b"db").is_ok());
+        assert!(db.put(&WriteOptions::default(), b"k2", b"db").is_ok());
+        assert!(db.put(&WriteOptions::default(), b"k3", b"db").is_ok());
+
+        let mut batch = WriteBatchWithIndex::new();
+        batch.put(b"k1", b"batch").delete(b"k2").put(b"k4", b"batch");
+        assert_eq!(batch.count(), 3);
+
+        // read back from the batch alone
+        let dbopts = DBOptions::default();
+        assert_eq!(batch.get_from_batch(&dbopts, b"k1").unwrap(), b"batch");
+        assert!(batch.get_from_batch(&dbopts, b"k2").unwrap_err().is_not_found());
+        assert!(batch.get_from_batch(&dbopts, b"k3").unwrap_err().is_not_found());
+        // not written yet
+        assert_eq!(db.get(&ReadOptions::default(), b"k1").unwrap(), b"db".as_ref());
+        assert!(db.get(&ReadOptions::default(), b"k4").unwrap_err().is_not_found());
+
+        // batch overlays DB state
+        let ropts = ReadOptions::default();
+        assert_eq!(batch.get_from_batch_and_db(&db, &ropts, b"k1").unwrap(), b"batch".as_ref());
+        assert!(batch.get_from_batch_and_db(&db, &ropts, b"k2").unwrap_err().is_not_found());
+        assert_eq!(batch.get_from_batch_and_db(&db, &ropts, b"k3").unwrap(), b"db".as_ref());
+        assert_eq!(batch.get_from_batch_and_db(&db, &ropts, b"k4").unwrap(), b"batch".as_ref());
+
+        assert!(db.write(&WriteOptions::default(), batch.get_write_batch()).is_ok());
+        assert_eq!(db.get(&ReadOptions::default(), b"k1").unwrap(), b"batch".as_ref());
+        assert!(db.get(&ReadOptions::default(), b"k2").unwrap_err().is_not_found());
+        assert_eq!(db.get(&ReadOptions::default(), b"k4").unwrap(), b"batch".as_ref());
+
+        batch.clear();
+        assert_eq!(batch.count(), 0);
+    }
+}
@@ -3,6 +3,7 @@
 
 use rocks_sys as ll;
 
+use cache::Cache;
 use to_raw::ToRaw;
 
 /// `WriteBufferManager` is for managing memory allocation for one or more
@@ -32,6 +33,14 @@ impl WriteBufferManager {
         WriteBufferManager { raw: unsafe { ll::rocks_write_buffer_manager_create(buffer_size) } }
     }
 
+    /// Like `new`, but also charges the memory of memtables to `cache`, so that
+    /// block cache and memtables share one memory budget.
+    pub fn with_cache(buffer_size: usize, cache: &Cache) -> WriteBufferManager {
+        WriteBufferManager {
+            raw: unsafe { ll::rocks_write_buffer_manager_create_with_cache(buffer_size, cache.raw()) },
+        }
+    }
+
     pub fn enabled(&self) -> bool {
         unsafe { ll::rocks_write_buffer_manager_enabled(self.raw) != 0 }
     }
@@ -108,4 +117,49 @@ mod tests {
         drop(db2);
         assert_eq!(manager.memory_usage(), 0);
     }
+
+    #[test]
+    fn write_buffer_manager_across_column_families() {
+        use cache::CacheBuilder;
+
+        let tmp_dir = ::tempdir::TempDir::new_in(".", "rocks").unwrap();
+        let cache = CacheBuilder::new_lru(16 << 20).build().unwrap();
+        let manager = WriteBufferManager::with_cache(1 << 20, &cache);
+        assert_eq!(manager.buffer_size(), 1 << 20);
+
+        // per-CF memtables alone would never fill up
+        let db = DB::open(
+            Options::default()
+                .map_db_options(|db| db.create_if_missing(true).write_buffer_manager(&manager))
+                .map_cf_options(|cf| cf.write_buffer_size(64 << 20)),
+            &tmp_dir,
+        ).unwrap();
+        let cfopts = ColumnFamilyOptions::default().write_buffer_size(64 << 20);
+        let cfs = (0..3)
+            .map(|i| db.create_column_family(&cfopts, &format!("cf{}", i)).unwrap())
+            .collect::<Vec<_>>();
+
+        let value = vec![b'v'; 1000];
+        for i in 0..3000 {
+            let key = format!("key-{:04}", i);
+            assert!(cfs[i % 3].put(&WriteOptions::default(), key.as_bytes(), &value).is_ok());
+        }
+        // memtable memory is charged to the block cache
+        assert!(cache.get_usage() > 0);
+        assert!(manager.memory_usage() > 0);
+
+        // the shared budget switched memtables on the write path, before any of them filled up
+        let active_entries = || {
+            cfs.iter()
+                .map(|cf| db.get_int_property_cf(cf, "rocksdb.num-entries-active-mem-table").unwrap())
+                .sum::<u64>()
+        };
+        assert!(active_entries() < 3000);
+
+        for cf in &cfs {
+            assert!(db.flush_cf(&FlushOptions::default().wait(true), cf).is_ok());
+        }
+        assert_eq!(active_entries(), 0);
+        assert!(cfs.iter().all(|cf| db.get_column_family_metadata(cf).file_count > 0));
+    }
 }